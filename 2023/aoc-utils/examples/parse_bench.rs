@@ -0,0 +1,111 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc_utils::{
+    bytes::{self, Grid},
+    rng::Rng,
+};
+use nom::{
+    character::complete::{space1, u64},
+    multi::separated_list1,
+    IResult,
+};
+
+fn time<T>(label: &str, run: impl Fn() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..5 {
+        black_box(run());
+    }
+    let elapsed = start.elapsed() / 5;
+    println!("{label:<28} {elapsed:>12?}");
+    elapsed
+}
+
+fn number_lines(target_len: usize) -> String {
+    let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+    let mut input = String::with_capacity(target_len + 64);
+    while input.len() < target_len {
+        for column in 0..12 {
+            if column > 0 {
+                input.push_str(&" ".repeat(1 + rng.below(4)));
+            }
+            input.push_str(&(rng.next_u64() % 10_000_000_000).to_string());
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn schematic(target_len: usize) -> String {
+    let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+    let width = 140;
+    let mut input = String::with_capacity(target_len + width);
+    while input.len() < target_len {
+        for _ in 0..width {
+            input.push(match rng.next_u64() % 10 {
+                0..=5 => '.',
+                6..=8 => char::from(b'0' + (rng.next_u64() % 10) as u8),
+                _ => '*',
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn nom_line(input: &str) -> IResult<&str, Vec<u64>> {
+    separated_list1(space1, u64)(input)
+}
+
+fn main() {
+    let numbers = number_lines(8 << 20);
+    println!(
+        "{} MiB of whitespace separated numbers",
+        numbers.len() >> 20
+    );
+    let nom = time("nom separated_list1", || {
+        numbers
+            .lines()
+            .map(|line| nom_line(line).unwrap().1.iter().sum::<u64>())
+            .sum::<u64>()
+    });
+    let str_parse = time("str split_whitespace+parse", || {
+        numbers
+            .split_whitespace()
+            .map(|number| number.parse::<u64>().unwrap())
+            .sum::<u64>()
+    });
+    let byte_scan = time("bytes::unsigneds", || {
+        bytes::unsigneds(numbers.as_bytes())
+            .sum::<Result<u64, _>>()
+            .unwrap()
+    });
+    println!(
+        "bytes::unsigneds is {:.1}x nom, {:.1}x str\n",
+        nom.as_secs_f64() / byte_scan.as_secs_f64(),
+        str_parse.as_secs_f64() / byte_scan.as_secs_f64()
+    );
+
+    let scheme = schematic(8 << 20);
+    println!("{} MiB schematic", scheme.len() >> 20);
+    let chars = time("Vec<Vec<char>> digit count", || {
+        let scheme: Vec<Vec<char>> = scheme
+            .split('\n')
+            .map(|row| row.chars().collect())
+            .collect();
+        scheme
+            .iter()
+            .map(|row| row.iter().filter(|char| char.is_ascii_digit()).count())
+            .sum::<usize>()
+    });
+    let grid = time("Grid digit count", || {
+        let grid = Grid::new(scheme.as_bytes());
+        grid.rows()
+            .map(|row| row.iter().filter(|byte| byte.is_ascii_digit()).count())
+            .sum::<usize>()
+    });
+    println!(
+        "Grid is {:.1}x Vec<Vec<char>>",
+        chars.as_secs_f64() / grid.as_secs_f64()
+    );
+}
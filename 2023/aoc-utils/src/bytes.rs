@@ -0,0 +1,250 @@
+use std::{error, fmt};
+
+/// Parses the unsigned integer at the start of `bytes`. Returns the value and
+/// how many bytes were consumed, or `None` if there is no digit or it overflows.
+pub fn parse_unsigned(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+    let mut consumed = 0;
+    for byte in bytes {
+        if !byte.is_ascii_digit() {
+            break;
+        }
+        value = value.checked_mul(10)?.checked_add(u64::from(byte - b'0'))?;
+        consumed += 1;
+    }
+    (consumed > 0).then_some((value, consumed))
+}
+
+/// Like [`parse_unsigned`] but accepts a leading `-` or `+`.
+pub fn parse_signed(bytes: &[u8]) -> Option<(i64, usize)> {
+    match bytes.first() {
+        Some(b'-') => {
+            let (value, consumed) = parse_unsigned(&bytes[1..])?;
+            let value = 0i64.checked_sub_unsigned(value)?;
+            Some((value, consumed + 1))
+        }
+        Some(b'+') => {
+            let (value, consumed) = parse_unsigned(&bytes[1..])?;
+            Some((i64::try_from(value).ok()?, consumed + 1))
+        }
+        _ => {
+            let (value, consumed) = parse_unsigned(bytes)?;
+            Some((i64::try_from(value).ok()?, consumed))
+        }
+    }
+}
+
+/// Reads every digit in `bytes` as one number, ignoring anything else, so
+/// `"7  15   30"` becomes `71530`.
+pub fn digits_to_unsigned(bytes: &[u8]) -> Option<u64> {
+    bytes
+        .iter()
+        .filter(|byte| byte.is_ascii_digit())
+        .try_fold(0u64, |acc, byte| {
+            acc.checked_mul(10)?.checked_add(u64::from(byte - b'0'))
+        })
+}
+
+/// A number too large for the integer type it was scanned into. `start..end`
+/// are the byte offsets of its sign and digits in the scanned buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "number at bytes {}..{} overflows", self.start, self.end)
+    }
+}
+
+impl error::Error for Overflow {}
+
+fn digit_run(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count()
+}
+
+/// Iterator over every run of digits in a buffer, skipping whatever separates
+/// them. A run that overflows is reported as an error and scanning carries on
+/// after it.
+#[derive(Debug, Clone)]
+pub struct Unsigneds<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+pub fn unsigneds(bytes: &[u8]) -> Unsigneds<'_> {
+    Unsigneds { bytes, offset: 0 }
+}
+
+impl Iterator for Unsigneds<'_> {
+    type Item = Result<u64, Overflow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.bytes.iter().position(u8::is_ascii_digit)?;
+        let end = start + digit_run(&self.bytes[start..]);
+        let value = parse_unsigned(&self.bytes[start..end]).map(|(value, _)| value);
+        let overflow = Overflow {
+            start: self.offset + start,
+            end: self.offset + end,
+        };
+        self.bytes = &self.bytes[end..];
+        self.offset += end;
+        Some(value.ok_or(overflow))
+    }
+}
+
+/// Iterator over every integer in a buffer, treating a `-` directly before a
+/// digit run as its sign. Overflows are reported like [`Unsigneds`] does.
+#[derive(Debug, Clone)]
+pub struct Signeds<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+pub fn signeds(bytes: &[u8]) -> Signeds<'_> {
+    Signeds { bytes, offset: 0 }
+}
+
+impl Iterator for Signeds<'_> {
+    type Item = Result<i64, Overflow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let digit = self.bytes.iter().position(u8::is_ascii_digit)?;
+        let start = if digit > 0 && self.bytes[digit - 1] == b'-' {
+            digit - 1
+        } else {
+            digit
+        };
+        let end = digit + digit_run(&self.bytes[digit..]);
+        let value = parse_signed(&self.bytes[start..end]).map(|(value, _)| value);
+        let overflow = Overflow {
+            start: self.offset + start,
+            end: self.offset + end,
+        };
+        self.bytes = &self.bytes[end..];
+        self.offset += end;
+        Some(value.ok_or(overflow))
+    }
+}
+
+/// Rectangular view over a newline separated buffer without copying it. Cell
+/// `(row, col)` lives at `row * stride + col`, where `stride` includes the line
+/// ending (`\n` or `\r\n`).
+#[derive(Debug, Clone, Copy)]
+pub struct Grid<'a> {
+    bytes: &'a [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+impl<'a> Grid<'a> {
+    /// Every line must be as long as the first one; trailing blank lines are ignored.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        let end = bytes.len()
+            - bytes
+                .iter()
+                .rev()
+                .take_while(|byte| **byte == b'\n' || **byte == b'\r')
+                .count();
+        let bytes = &bytes[..end];
+        let Some(line_end) = bytes.iter().position(|byte| *byte == b'\n') else {
+            return Grid {
+                bytes,
+                width: bytes.len(),
+                height: usize::from(!bytes.is_empty()),
+                stride: bytes.len() + 1,
+            };
+        };
+        let (width, stride) = if line_end > 0 && bytes[line_end - 1] == b'\r' {
+            (line_end - 1, line_end + 1)
+        } else {
+            (line_end, line_end + 1)
+        };
+        Grid {
+            bytes,
+            width,
+            height: (bytes.len() + stride - width) / stride,
+            stride,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<u8> {
+        if row < self.height && col < self.width {
+            Some(self.bytes[row * self.stride + col])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &'a [u8] {
+        let start = row * self.stride;
+        &self.bytes[start..start + self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Converts a byte offset into the underlying buffer back into `(row, col)`.
+    pub fn position(&self, offset: usize) -> (usize, usize) {
+        (offset / self.stride, offset % self.stride)
+    }
+
+    pub fn find(&self, needle: u8) -> Option<(usize, usize)> {
+        self.bytes
+            .iter()
+            .position(|byte| *byte == needle)
+            .map(|offset| self.position(offset))
+    }
+
+    /// The up to eight cells surrounding `(row, col)` that lie inside the grid.
+    pub fn neighbours(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (row.saturating_sub(1)..=row + 1)
+            .flat_map(move |neighbour_row| {
+                (col.saturating_sub(1)..=col + 1)
+                    .map(move |neighbour_col| (neighbour_row, neighbour_col))
+            })
+            .filter(move |(neighbour_row, neighbour_col)| {
+                (*neighbour_row, *neighbour_col) != (row, col)
+                    && *neighbour_row < self.height
+                    && *neighbour_col < self.width
+            })
+    }
+
+    /// The unsigned number whose digits cover `(row, col)`, with the column span it occupies.
+    pub fn number_at(&self, row: usize, col: usize) -> Option<(u64, usize, usize)> {
+        if row >= self.height {
+            return None;
+        }
+        let line = self.row(row);
+        if !line.get(col)?.is_ascii_digit() {
+            return None;
+        }
+        let start = col
+            - line[..col]
+                .iter()
+                .rev()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+        let (value, consumed) = parse_unsigned(&line[start..])?;
+        Some((value, start, start + consumed - 1))
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Shape of the sequence `x0, f(x0), f(f(x0)), ...`: `prefix_len` steps lead into
+/// a loop of `cycle_len` states. Hits are step indices where the predicate held.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix_len: usize,
+    pub cycle_len: usize,
+    pub prefix_hits: Vec<usize>,
+    pub cycle_hits: Vec<usize>,
+}
+
+impl Cycle {
+    /// Index within `0..prefix_len + cycle_len` that holds the same state as step `step`.
+    pub fn canonical_step(&self, step: usize) -> usize {
+        if step < self.prefix_len {
+            step
+        } else {
+            self.prefix_len + (step - self.prefix_len) % self.cycle_len
+        }
+    }
+
+    pub fn holds_at(&self, step: usize) -> bool {
+        let step = self.canonical_step(step);
+        if step < self.prefix_len {
+            self.prefix_hits.binary_search(&step).is_ok()
+        } else {
+            self.cycle_hits.binary_search(&step).is_ok()
+        }
+    }
+}
+
+/// Floyd's tortoise and hare. Returns `(prefix_len, cycle_len)`.
+pub fn floyd<S: Clone + Eq>(start: S, step: impl Fn(&S) -> S) -> (usize, usize) {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&step(&hare));
+    }
+
+    let mut prefix_len = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+
+    let mut cycle_len = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+
+    (prefix_len, cycle_len)
+}
+
+/// Brent's algorithm, usually fewer transition calls than Floyd. Returns
+/// `(prefix_len, cycle_len)`.
+pub fn brent<S: Clone + Eq>(start: S, step: impl Fn(&S) -> S) -> (usize, usize) {
+    let mut power = 1;
+    let mut cycle_len = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == cycle_len {
+            tortoise = hare.clone();
+            power *= 2;
+            cycle_len = 0;
+        }
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..cycle_len {
+        hare = step(&hare);
+    }
+    let mut prefix_len = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+
+    (prefix_len, cycle_len)
+}
+
+/// Finds the cycle with Brent's algorithm, then walks it once more to record
+/// every step where `predicate` holds.
+pub fn detect<S: Clone + Eq>(
+    start: S,
+    step: impl Fn(&S) -> S,
+    predicate: impl Fn(&S) -> bool,
+) -> Cycle {
+    let (prefix_len, cycle_len) = brent(start.clone(), &step);
+    let mut prefix_hits = vec![];
+    let mut cycle_hits = vec![];
+    let mut state = start;
+    for index in 0..prefix_len + cycle_len {
+        if predicate(&state) {
+            if index < prefix_len {
+                prefix_hits.push(index);
+            } else {
+                cycle_hits.push(index);
+            }
+        }
+        state = step(&state);
+    }
+
+    Cycle {
+        prefix_len,
+        cycle_len,
+        prefix_hits,
+        cycle_hits,
+    }
+}
+
+/// Same result as [`detect`] but remembers every visited state, so each
+/// transition runs exactly once. Better when `step` is expensive and the
+/// states are cheap to hash.
+pub fn detect_hashed<S: Clone + Hash + Eq>(
+    start: S,
+    step: impl Fn(&S) -> S,
+    predicate: impl Fn(&S) -> bool,
+) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut hits = vec![];
+    let mut state = start;
+    let mut index = 0;
+    let prefix_len = loop {
+        if let Some(first_seen) = seen.insert(state.clone(), index) {
+            break first_seen;
+        }
+        if predicate(&state) {
+            hits.push(index);
+        }
+        state = step(&state);
+        index += 1;
+    };
+    let split = hits.partition_point(|hit| *hit < prefix_len);
+    let cycle_hits = hits.split_off(split);
+
+    Cycle {
+        prefix_len,
+        cycle_len: index - prefix_len,
+        prefix_hits: hits,
+        cycle_hits,
+    }
+}
+
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+// Merges n = a (mod m) with n = b (mod n) into a single congruence, if one exists.
+fn merge_congruence((a, m): (i128, i128), (b, n): (i128, i128)) -> Option<(i128, i128)> {
+    let (g, p, _) = extended_gcd(m, n);
+    if (b - a) % g != 0 {
+        return None;
+    }
+    let lcm = m / g * n;
+    let offset = ((b - a) / g % (n / g)) * p % (n / g);
+    Some(((a + m * offset).rem_euclid(lcm), lcm))
+}
+
+/// First step at which the predicate holds for every cycle at once, solving the
+/// cycle part with the Chinese remainder theorem instead of assuming that the
+/// prefix length equals the cycle length.
+pub fn first_common_hit(cycles: &[Cycle]) -> Option<usize> {
+    let longest_prefix = cycles.iter().map(|cycle| cycle.prefix_len).max()?;
+
+    // Before every sequence is inside its loop the first one's hits are few
+    // enough to check directly.
+    let first = &cycles[0];
+    let early = (0..longest_prefix)
+        .filter(|step| first.holds_at(*step))
+        .find(|step| cycles.iter().all(|cycle| cycle.holds_at(*step)));
+    if early.is_some() {
+        return early;
+    }
+
+    let mut congruences = vec![(0i128, 1i128)];
+    for cycle in cycles {
+        congruences = congruences
+            .iter()
+            .flat_map(|congruence| {
+                cycle.cycle_hits.iter().filter_map(|hit| {
+                    merge_congruence(*congruence, (*hit as i128, cycle.cycle_len as i128))
+                })
+            })
+            .collect();
+        if congruences.is_empty() {
+            return None;
+        }
+    }
+
+    congruences
+        .iter()
+        .map(|(residue, modulus)| {
+            let floor = longest_prefix as i128;
+            if *residue >= floor {
+                *residue
+            } else {
+                residue + (floor - residue + modulus - 1) / modulus * modulus
+            }
+        })
+        .min()
+        .map(|step| step as usize)
+}
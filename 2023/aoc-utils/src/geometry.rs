@@ -0,0 +1,221 @@
+use std::ops::{Add, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point {
+            x: i64::try_from(x).expect("Should fit in i64"),
+            y: i64::try_from(y).expect("Should fit in i64"),
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn edges(polygon: &[Point]) -> impl Iterator<Item = (&Point, &Point)> {
+    polygon.iter().zip(polygon.iter().cycle().skip(1))
+}
+
+/// Twice the signed area of a closed polygon (shoelace formula).
+/// Positive when the vertices are in counter-clockwise order (y up).
+pub fn signed_double_area(polygon: &[Point]) -> i64 {
+    edges(polygon).map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+}
+
+/// Twice the area of a closed polygon, kept as an integer so odd areas stay exact.
+pub fn double_area(polygon: &[Point]) -> u64 {
+    signed_double_area(polygon).unsigned_abs()
+}
+
+pub fn area(polygon: &[Point]) -> f64 {
+    double_area(polygon) as f64 / 2.0
+}
+
+/// Number of lattice points lying on the polygon's edges.
+pub fn boundary_points(polygon: &[Point]) -> u64 {
+    edges(polygon)
+        .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)))
+        .sum()
+}
+
+/// Number of lattice points strictly inside the polygon, via Pick's theorem
+/// (`A = I + B / 2 - 1`). `None` when the polygon encloses no area, such as
+/// fewer than three vertices or all of them on one line, since Pick's theorem
+/// doesn't hold there.
+pub fn interior_points(polygon: &[Point]) -> Option<u64> {
+    if polygon.len() < 3 {
+        return None;
+    }
+    (double_area(polygon) + 2)
+        .checked_sub(boundary_points(polygon))
+        .map(|twice_interior| twice_interior / 2)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Containment {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+fn on_segment(point: &Point, a: &Point, b: &Point) -> bool {
+    let cross = (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x);
+    cross == 0
+        && point.x >= a.x.min(b.x)
+        && point.x <= a.x.max(b.x)
+        && point.y >= a.y.min(b.y)
+        && point.y <= a.y.max(b.y)
+}
+
+pub fn locate(polygon: &[Point], point: &Point) -> Containment {
+    let mut inside = false;
+    for (a, b) in edges(polygon) {
+        if on_segment(point, a, b) {
+            return Containment::Boundary;
+        }
+        if (a.y > point.y) != (b.y > point.y) {
+            // x coordinate where the edge crosses the horizontal ray, compared
+            // without dividing so the test stays exact
+            let lhs = (point.x - a.x) * (b.y - a.y);
+            let rhs = (b.x - a.x) * (point.y - a.y);
+            if (b.y > a.y && lhs < rhs) || (b.y < a.y && lhs > rhs) {
+                inside = !inside;
+            }
+        }
+    }
+    if inside {
+        Containment::Inside
+    } else {
+        Containment::Outside
+    }
+}
+
+pub fn contains(polygon: &[Point], point: &Point) -> bool {
+    locate(polygon, point) == Containment::Inside
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl BoundingBox {
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Point>) -> Option<Self> {
+        points.into_iter().fold(None, |acc, point| match acc {
+            None => Some(BoundingBox {
+                min: *point,
+                max: *point,
+            }),
+            Some(bounding_box) => Some(BoundingBox {
+                min: Point::new(
+                    bounding_box.min.x.min(point.x),
+                    bounding_box.min.y.min(point.y),
+                ),
+                max: Point::new(
+                    bounding_box.max.x.max(point.x),
+                    bounding_box.max.y.max(point.y),
+                ),
+            }),
+        })
+    }
+
+    pub fn width(&self) -> u64 {
+        self.min.x.abs_diff(self.max.x) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        self.min.y.abs_diff(self.max.y) + 1
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+}
+
+// Sum of |a - b| over every unordered pair, by sorting and counting how many
+// values sit to the left of each one.
+fn axis_pair_sum(mut values: Vec<i64>) -> u64 {
+    values.sort_unstable();
+    values
+        .iter()
+        .enumerate()
+        .fold((0u64, 0i128), |(total, prefix), (index, value)| {
+            let contribution = i128::from(*value) * index as i128 - prefix;
+            (total + contribution as u64, prefix + i128::from(*value))
+        })
+        .0
+}
+
+/// Sum of Manhattan distances over every unordered pair of points, in O(n log n).
+pub fn manhattan_pair_sum(points: &[Point]) -> u64 {
+    axis_pair_sum(points.iter().map(|point| point.x).collect())
+        + axis_pair_sum(points.iter().map(|point| point.y).collect())
+}
+
+/// Sum of Chebyshev distances over every unordered pair of points. Rotating by
+/// 45 degrees (`u = x + y`, `v = x - y`) turns Chebyshev into half the Manhattan
+/// distance.
+pub fn chebyshev_pair_sum(points: &[Point]) -> u64 {
+    let rotated: Vec<Point> = points
+        .iter()
+        .map(|point| Point::new(point.x + point.y, point.x - point.y))
+        .collect();
+    manhattan_pair_sum(&rotated) / 2
+}
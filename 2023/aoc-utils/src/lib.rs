@@ -0,0 +1,6 @@
+pub mod bytes;
+pub mod cycle;
+pub mod geometry;
+pub mod memo;
+pub mod render;
+pub mod rng;
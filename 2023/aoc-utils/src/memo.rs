@@ -0,0 +1,125 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub len: usize,
+}
+
+/// Cache for memoized recursion. Create one per top-level call and thread it
+/// through the recursive function:
+///
+/// ```
+/// use aoc_utils::memo::Memo;
+///
+/// fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(&n, |memo, n| match n {
+///         0 | 1 => *n,
+///         n => fib(memo, n - 1) + fib(memo, n - 2),
+///     })
+/// }
+///
+/// assert_eq!(fib(&mut Memo::new(), 90), 2880067194370816120);
+/// ```
+///
+/// Keys are looked up by any borrowed form of `K`, so a `Memo<Vec<i64>, _>`
+/// can be queried with a `&[i64]` and only allocates on a miss.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    order: VecDeque<K>,
+    capacity: Option<usize>,
+    stats: CacheStats,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: None,
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Keeps at most `capacity` entries, evicting the oldest insertion first.
+    pub fn bounded(capacity: usize) -> Self {
+        Memo {
+            capacity: Some(capacity),
+            ..Self::new()
+        }
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.cache.get(key)
+    }
+
+    pub fn get_or_compute<Q>(&mut self, key: &Q, compute: impl FnOnce(&mut Self, &Q) -> V) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(value) = self.cache.get(key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = compute(self, key);
+        self.insert(key.to_owned(), value.clone());
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        if let Some(capacity) = self.capacity {
+            if capacity == 0 {
+                return;
+            }
+            if !self.cache.contains_key(&key) {
+                while self.cache.len() >= capacity {
+                    let Some(oldest) = self.order.pop_front() else {
+                        break;
+                    };
+                    self.cache.remove(&oldest);
+                    self.stats.evictions += 1;
+                }
+                self.order.push_back(key.clone());
+            }
+        }
+        self.cache.insert(key, value);
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            len: self.cache.len(),
+            ..self.stats
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.order.clear();
+        self.stats = CacheStats::default();
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,429 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+
+use crate::bytes::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    fn sgr(&self, background: bool) -> String {
+        let offset = if background { 10 } else { 0 };
+        let code = match self {
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+            Color::BrightBlack => 90,
+            Color::BrightRed => 91,
+            Color::BrightGreen => 92,
+            Color::BrightYellow => 93,
+            Color::BrightBlue => 94,
+            Color::BrightMagenta => 95,
+            Color::BrightCyan => 96,
+            Color::BrightWhite => 97,
+            Color::Rgb(r, g, b) => return format!("{};2;{r};{g};{b}", 38 + offset),
+        };
+        (code + offset).to_string()
+    }
+
+    // The xterm defaults, so the HTML page looks like the terminal.
+    fn css(&self) -> String {
+        let (r, g, b) = match *self {
+            Color::Black => (0, 0, 0),
+            Color::Red => (205, 0, 0),
+            Color::Green => (0, 205, 0),
+            Color::Yellow => (205, 205, 0),
+            Color::Blue => (0, 0, 238),
+            Color::Magenta => (205, 0, 205),
+            Color::Cyan => (0, 205, 205),
+            Color::White => (229, 229, 229),
+            Color::BrightBlack => (127, 127, 127),
+            Color::BrightRed => (255, 0, 0),
+            Color::BrightGreen => (0, 255, 0),
+            Color::BrightYellow => (255, 255, 0),
+            Color::BrightBlue => (92, 92, 255),
+            Color::BrightMagenta => (255, 0, 255),
+            Color::BrightCyan => (0, 255, 255),
+            Color::BrightWhite => (255, 255, 255),
+            Color::Rgb(r, g, b) => (r, g, b),
+        };
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub fn fg(color: Color) -> Self {
+        Style {
+            fg: Some(color),
+            ..Style::default()
+        }
+    }
+
+    pub fn bg(color: Color) -> Self {
+        Style {
+            bg: Some(color),
+            ..Style::default()
+        }
+    }
+
+    pub fn bold(self) -> Self {
+        Style { bold: true, ..self }
+    }
+
+    /// Fields set on `other` win.
+    pub fn merge(self, other: Style) -> Self {
+        Style {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            bold: self.bold || other.bold,
+        }
+    }
+
+    fn escape(&self) -> String {
+        let mut codes = vec![];
+        if self.bold {
+            codes.push("1".to_owned());
+        }
+        if let Some(fg) = self.fg {
+            codes.push(fg.sgr(false));
+        }
+        if let Some(bg) = self.bg {
+            codes.push(bg.sgr(true));
+        }
+        format!("\x1b[{}m", codes.join(";"))
+    }
+
+    fn css(&self) -> String {
+        let mut rules = vec![];
+        if self.bold {
+            rules.push("font-weight: bold".to_owned());
+        }
+        if let Some(fg) = self.fg {
+            rules.push(format!("color: {}", fg.css()));
+        }
+        if let Some(bg) = self.bg {
+            rules.push(format!("background: {}", bg.css()));
+        }
+        rules.join("; ")
+    }
+}
+
+/// Anything that can be drawn cell by cell. Only `glyph` is required; `style`
+/// lets a day colour its own state.
+pub trait GridView {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    fn glyph(&self, row: usize, col: usize) -> char;
+
+    fn style(&self, _row: usize, _col: usize) -> Style {
+        Style::default()
+    }
+}
+
+impl GridView for Grid<'_> {
+    fn width(&self) -> usize {
+        Grid::width(self)
+    }
+
+    fn height(&self) -> usize {
+        Grid::height(self)
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        char::from(self.get(row, col).unwrap_or(b' '))
+    }
+}
+
+impl GridView for Vec<Vec<char>> {
+    fn width(&self) -> usize {
+        self.iter().map(|row| row.len()).max().unwrap_or_default()
+    }
+
+    fn height(&self) -> usize {
+        self.len()
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        self[row].get(col).copied().unwrap_or(' ')
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub row: usize,
+    pub col: usize,
+    pub height: usize,
+    pub width: usize,
+}
+
+impl Viewport {
+    pub fn centered_on(row: usize, col: usize, height: usize, width: usize) -> Self {
+        Viewport {
+            row: row.saturating_sub(height / 2),
+            col: col.saturating_sub(width / 2),
+            height,
+            width,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Renderer {
+    glyphs: HashMap<char, char>,
+    styles: HashMap<char, Style>,
+    highlights: HashMap<(usize, usize), Style>,
+    viewport: Option<Viewport>,
+    plain: bool,
+}
+
+impl Renderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draws `from` as `to`, e.g. `'F'` as `'┌'`.
+    pub fn glyph(mut self, from: char, to: char) -> Self {
+        self.glyphs.insert(from, to);
+        self
+    }
+
+    pub fn glyphs(mut self, mapping: impl IntoIterator<Item = (char, char)>) -> Self {
+        self.glyphs.extend(mapping);
+        self
+    }
+
+    /// Styles every cell showing `glyph` (matched before glyph mapping).
+    pub fn style(mut self, glyph: char, style: Style) -> Self {
+        self.styles.insert(glyph, style);
+        self
+    }
+
+    pub fn highlight(mut self, row: usize, col: usize, style: Style) -> Self {
+        self.highlights.insert((row, col), style);
+        self
+    }
+
+    pub fn highlight_all(
+        mut self,
+        cells: impl IntoIterator<Item = (usize, usize)>,
+        style: Style,
+    ) -> Self {
+        self.highlights
+            .extend(cells.into_iter().map(|cell| (cell, style)));
+        self
+    }
+
+    pub fn viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    /// Drops all escape codes, for logs or terminals without colour.
+    pub fn plain(mut self) -> Self {
+        self.plain = true;
+        self
+    }
+
+    fn visible(&self, view: &impl GridView) -> (Range<usize>, Range<usize>) {
+        let viewport = self.viewport.unwrap_or(Viewport {
+            row: 0,
+            col: 0,
+            height: view.height(),
+            width: view.width(),
+        });
+        let rows =
+            viewport.row.min(view.height())..(viewport.row + viewport.height).min(view.height());
+        let cols =
+            viewport.col.min(view.width())..(viewport.col + viewport.width).min(view.width());
+        (rows, cols)
+    }
+
+    fn cell(&self, view: &impl GridView, row: usize, col: usize) -> (char, Style) {
+        let raw = view.glyph(row, col);
+        let glyph = self.glyphs.get(&raw).copied().unwrap_or(raw);
+        let mut style = view.style(row, col);
+        if let Some(glyph_style) = self.styles.get(&raw) {
+            style = style.merge(*glyph_style);
+        }
+        if let Some(highlight) = self.highlights.get(&(row, col)) {
+            style = style.merge(*highlight);
+        }
+        (glyph, style)
+    }
+
+    pub fn render(&self, view: &impl GridView) -> Frame {
+        let (rows, cols) = self.visible(view);
+        let width = cols.len();
+
+        let lines = rows
+            .map(|row| {
+                let mut line = String::new();
+                let mut current = Style::default();
+                for col in cols.clone() {
+                    let (glyph, style) = self.cell(view, row, col);
+                    if !self.plain && style != current {
+                        if current != Style::default() {
+                            line.push_str("\x1b[0m");
+                        }
+                        if style != Style::default() {
+                            line.push_str(&style.escape());
+                        }
+                        current = style;
+                    }
+                    line.push(glyph);
+                }
+                if !self.plain && current != Style::default() {
+                    line.push_str("\x1b[0m");
+                }
+                line
+            })
+            .collect();
+
+        Frame { lines, width }
+    }
+
+    /// The same view as `render`, as a standalone HTML page with one `<span>`
+    /// per run of equally styled cells. `plain` drops the styling here too.
+    pub fn render_html(&self, view: &impl GridView, title: &str) -> String {
+        let (rows, cols) = self.visible(view);
+        let mut body = String::new();
+        for row in rows {
+            let mut current = Style::default();
+            for col in cols.clone() {
+                let (glyph, style) = self.cell(view, row, col);
+                let style = if self.plain { Style::default() } else { style };
+                if style != current {
+                    if current != Style::default() {
+                        body.push_str("</span>");
+                    }
+                    if style != Style::default() {
+                        body.push_str(&format!("<span style=\"{}\">", style.css()));
+                    }
+                    current = style;
+                }
+                push_html_escaped(&mut body, glyph);
+            }
+            if current != Style::default() {
+                body.push_str("</span>");
+            }
+            body.push('\n');
+        }
+
+        let mut title_html = String::new();
+        title
+            .chars()
+            .for_each(|char| push_html_escaped(&mut title_html, char));
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title_html}</title>\n\
+             <style>body {{ background: #1e1e1e; color: #d4d4d4; }} \
+             pre {{ font-family: monospace; line-height: 1.2; }}</style>\n\
+             </head>\n<body>\n<pre>\n{body}</pre>\n</body>\n</html>\n"
+        )
+    }
+}
+
+fn push_html_escaped(html: &mut String, char: char) {
+    match char {
+        '&' => html.push_str("&amp;"),
+        '<' => html.push_str("&lt;"),
+        '>' => html.push_str("&gt;"),
+        '"' => html.push_str("&quot;"),
+        _ => html.push(char),
+    }
+}
+
+/// A rendered grid. `width` is the number of visible cells per line, which
+/// differs from the byte length once escape codes are involved.
+#[derive(Debug, Clone, Default)]
+pub struct Frame {
+    pub lines: Vec<String>,
+    pub width: usize,
+}
+
+impl Frame {
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.width = self.width.max(title.chars().count());
+        self.lines.insert(0, title.to_owned());
+        self
+    }
+
+    /// Lays frames out left to right, `gap` spaces apart, padding shorter ones.
+    pub fn side_by_side(frames: &[Frame], gap: usize) -> Frame {
+        let height = frames
+            .iter()
+            .map(|frame| frame.lines.len())
+            .max()
+            .unwrap_or_default();
+        let lines = (0..height)
+            .map(|index| {
+                frames
+                    .iter()
+                    .map(|frame| {
+                        let line = frame.lines.get(index).map(String::as_str).unwrap_or("");
+                        let visible = visible_len(line);
+                        format!("{line}{}", " ".repeat(frame.width.saturating_sub(visible)))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(&" ".repeat(gap))
+            })
+            .collect();
+        let width = frames.iter().map(|frame| frame.width).sum::<usize>()
+            + gap * frames.len().saturating_sub(1);
+
+        Frame { lines, width }
+    }
+}
+
+fn visible_len(line: &str) -> usize {
+    let mut len = 0;
+    let mut in_escape = false;
+    for char in line.chars() {
+        match (in_escape, char) {
+            (false, '\x1b') => in_escape = true,
+            (true, 'm') => in_escape = false,
+            (true, _) => (),
+            (false, _) => len += 1,
+        }
+    }
+    len
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+/// xorshift64: a seeded generator for tests, benchmarks and synthetic inputs
+/// that must come out the same on every run, without pulling in `rand`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// Zero is a fixed point of xorshift, so a zero seed is treated as one.
+    pub fn new(seed: u64) -> Self {
+        Rng(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A value in `0..bound`, by modulo, which is close enough to uniform for
+    /// small bounds. Panics if `bound` is zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
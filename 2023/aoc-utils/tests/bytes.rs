@@ -0,0 +1,92 @@
+use aoc_utils::bytes::{self, Grid, Overflow};
+
+#[test]
+fn scans_numbers_between_any_separators() {
+    let unsigneds: Result<Vec<u64>, Overflow> = bytes::unsigneds(b"Time:  7 15\r\n30,x4").collect();
+    assert_eq!(unsigneds, Ok(vec![7, 15, 30, 4]));
+    let signeds: Result<Vec<i64>, Overflow> = bytes::signeds(b"x=-3, y=+4 -z 5-6").collect();
+    assert_eq!(signeds, Ok(vec![-3, 4, 5, -6]));
+}
+
+#[test]
+fn overflow_is_reported_and_scanning_continues() {
+    let input = b"1 18446744073709551616 2";
+    let values: Vec<Result<u64, Overflow>> = bytes::unsigneds(input).collect();
+    assert_eq!(
+        values,
+        vec![Ok(1), Err(Overflow { start: 2, end: 22 }), Ok(2)]
+    );
+    assert_eq!(
+        bytes::unsigneds(b"18446744073709551615").collect::<Vec<_>>(),
+        vec![Ok(u64::MAX)]
+    );
+}
+
+#[test]
+fn signed_overflow_covers_the_sign() {
+    let input = b"-9223372036854775808 -9223372036854775809 9223372036854775808 7";
+    let values: Vec<Result<i64, Overflow>> = bytes::signeds(input).collect();
+    assert_eq!(
+        values,
+        vec![
+            Ok(i64::MIN),
+            Err(Overflow { start: 21, end: 41 }),
+            Err(Overflow { start: 42, end: 61 }),
+            Ok(7),
+        ]
+    );
+}
+
+const SCHEMATIC: &str = "467..114..\n...*......\n..35..633.\n";
+
+#[test]
+fn grid_dimensions_skip_line_endings() {
+    let grid = Grid::new(SCHEMATIC.as_bytes());
+    assert_eq!((grid.width(), grid.height(), grid.stride()), (10, 3, 11));
+    let crlf = SCHEMATIC.replace('\n', "\r\n");
+    let grid = Grid::new(crlf.as_bytes());
+    assert_eq!((grid.width(), grid.height(), grid.stride()), (10, 3, 12));
+    assert_eq!(grid.row(2), b"..35..633.");
+    assert_eq!(grid.get(1, 3), Some(b'*'));
+    assert_eq!(grid.get(1, 10), None);
+    assert_eq!(grid.get(3, 0), None);
+    // Trailing blank lines are not rows.
+    let grid = Grid::new(b"ab\ncd\n\n\n");
+    assert_eq!(grid.rows().collect::<Vec<_>>(), [b"ab", b"cd"]);
+    assert_eq!(Grid::new(b"").height(), 0);
+    assert_eq!(Grid::new(b"abc").height(), 1);
+}
+
+#[test]
+fn offsets_map_back_to_cells() {
+    let grid = Grid::new(SCHEMATIC.as_bytes());
+    assert_eq!(grid.find(b'*'), Some((1, 3)));
+    assert_eq!(grid.position(2 * 11 + 6), (2, 6));
+    let crlf = SCHEMATIC.replace('\n', "\r\n");
+    let grid = Grid::new(crlf.as_bytes());
+    assert_eq!(grid.find(b'*'), Some((1, 3)));
+    assert_eq!(grid.find(b'#'), None);
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = Grid::new(SCHEMATIC.as_bytes());
+    let corner: Vec<_> = grid.neighbours(0, 0).collect();
+    assert_eq!(corner, [(0, 1), (1, 0), (1, 1)]);
+    assert_eq!(grid.neighbours(2, 9).count(), 3);
+    assert_eq!(grid.neighbours(0, 5).count(), 5);
+    assert_eq!(grid.neighbours(1, 3).count(), 8);
+}
+
+#[test]
+fn number_at_covers_every_digit() {
+    let grid = Grid::new(SCHEMATIC.as_bytes());
+    for col in 0..3 {
+        assert_eq!(grid.number_at(0, col), Some((467, 0, 2)));
+    }
+    assert_eq!(grid.number_at(2, 7), Some((633, 6, 8)));
+    assert_eq!(grid.number_at(0, 3), None);
+    assert_eq!(grid.number_at(0, 10), None);
+    assert_eq!(grid.number_at(3, 0), None);
+    assert_eq!(grid.number_at(20, 0), None);
+}
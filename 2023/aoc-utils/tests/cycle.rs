@@ -0,0 +1,116 @@
+use aoc_utils::cycle::{self, Cycle};
+
+// States 0, 1, .. count up to `prefix_len + cycle_len - 1`, then jump back to
+// `prefix_len`, so the sequence has exactly that shape.
+fn ring(prefix_len: usize, cycle_len: usize) -> impl Fn(&usize) -> usize {
+    move |state| {
+        if state + 1 < prefix_len + cycle_len {
+            state + 1
+        } else {
+            prefix_len
+        }
+    }
+}
+
+fn both(start: usize, step: impl Fn(&usize) -> usize) -> [(usize, usize); 2] {
+    [cycle::floyd(start, &step), cycle::brent(start, &step)]
+}
+
+#[test]
+fn pure_cycle() {
+    assert_eq!(both(0, ring(0, 5)), [(0, 5); 2]);
+    // Starting part way round is still a pure cycle.
+    assert_eq!(both(3, ring(0, 5)), [(0, 5); 2]);
+}
+
+#[test]
+fn cycle_with_a_tail() {
+    assert_eq!(both(0, ring(3, 4)), [(3, 4); 2]);
+    assert_eq!(both(0, ring(10, 7)), [(10, 7); 2]);
+    assert_eq!(both(2, ring(10, 7)), [(8, 7); 2]);
+}
+
+#[test]
+fn cycle_of_length_one() {
+    assert_eq!(both(0, ring(6, 1)), [(6, 1); 2]);
+    assert_eq!(both(0, |state: &usize| *state), [(0, 1); 2]);
+}
+
+#[test]
+fn detect_splits_hits_between_tail_and_cycle() {
+    let even = |state: &usize| state.is_multiple_of(2);
+    let expected = Cycle {
+        prefix_len: 3,
+        cycle_len: 4,
+        prefix_hits: vec![0, 2],
+        cycle_hits: vec![4, 6],
+    };
+    assert_eq!(cycle::detect(0, ring(3, 4), even), expected);
+    assert_eq!(cycle::detect_hashed(0, ring(3, 4), even), expected);
+    assert!(expected.holds_at(2));
+    assert!(!expected.holds_at(3));
+    // Step 10 is the same state as step 6.
+    assert_eq!(expected.canonical_step(10), 6);
+    assert!(expected.holds_at(10));
+}
+
+#[test]
+fn ghosts_that_loop_back_to_the_start_meet_at_the_lcm() {
+    // Like the day 8 ghosts: one step off the start, then a loop whose only
+    // hit closes it, so every ghost is on a hit at multiples of its length.
+    let cycles: Vec<Cycle> = [4, 6, 9]
+        .into_iter()
+        .map(|cycle_len| cycle::detect(0, ring(1, cycle_len), |state| *state == cycle_len))
+        .collect();
+    assert_eq!(cycles[0].cycle_hits, vec![4]);
+    assert_eq!(cycle::first_common_hit(&cycles), Some(36));
+}
+
+#[test]
+fn chinese_remainder_with_offsets() {
+    let cycles = [
+        Cycle {
+            prefix_len: 0,
+            cycle_len: 4,
+            prefix_hits: vec![],
+            cycle_hits: vec![3],
+        },
+        Cycle {
+            prefix_len: 0,
+            cycle_len: 6,
+            prefix_hits: vec![],
+            cycle_hits: vec![5],
+        },
+    ];
+    // n = 3 (mod 4) and n = 5 (mod 6)
+    assert_eq!(cycle::first_common_hit(&cycles), Some(11));
+}
+
+#[test]
+fn incompatible_cycles_never_meet() {
+    let cycles = [
+        Cycle {
+            prefix_len: 0,
+            cycle_len: 2,
+            prefix_hits: vec![],
+            cycle_hits: vec![0],
+        },
+        Cycle {
+            prefix_len: 0,
+            cycle_len: 4,
+            prefix_hits: vec![],
+            cycle_hits: vec![1],
+        },
+    ];
+    assert_eq!(cycle::first_common_hit(&cycles), None);
+}
+
+#[test]
+fn hits_in_the_tail_come_first() {
+    let cycles = [
+        cycle::detect(0, ring(5, 3), |state| *state == 2 || *state == 6),
+        cycle::detect(0, ring(2, 4), |state| *state == 2),
+    ];
+    // Both sequences are on state 2 at step 2, before either is looping.
+    assert_eq!(cycle::first_common_hit(&cycles), Some(2));
+}
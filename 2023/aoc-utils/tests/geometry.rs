@@ -0,0 +1,102 @@
+use aoc_utils::geometry::{self, Point};
+
+// The ENCLOSED loop from the 2023 day 10 README by its bends, walked from `S`
+// in the top left corner. Day 10's own tests trace the full sketches.
+fn enclosed() -> Vec<Point> {
+    [
+        (1, 1),
+        (9, 1),
+        (9, 7),
+        (6, 7),
+        (6, 5),
+        (8, 5),
+        (8, 2),
+        (2, 2),
+        (2, 5),
+        (4, 5),
+        (4, 7),
+        (1, 7),
+    ]
+    .into_iter()
+    .map(|(x, y)| Point::new(x, y))
+    .collect()
+}
+
+#[test]
+fn enclosed_loop_counts() {
+    let polygon = enclosed();
+    assert_eq!(geometry::boundary_points(&polygon), 46);
+    assert_eq!(geometry::double_area(&polygon), 52);
+    assert_eq!(geometry::area(&polygon), 26.0);
+    assert_eq!(geometry::interior_points(&polygon), Some(4));
+}
+
+#[test]
+fn walking_direction_only_flips_the_sign() {
+    let polygon = enclosed();
+    let reversed: Vec<Point> = polygon.iter().rev().copied().collect();
+    assert_eq!(
+        geometry::signed_double_area(&polygon),
+        -geometry::signed_double_area(&reversed)
+    );
+    assert_eq!(geometry::interior_points(&reversed), Some(4));
+}
+
+#[test]
+fn corners_alone_give_the_same_counts() {
+    // The SQUARE loop by its four bends: B counts the edge points that the
+    // full tile walk lists one by one.
+    let corners = [
+        Point::new(1, 1),
+        Point::new(3, 1),
+        Point::new(3, 3),
+        Point::new(1, 3),
+    ];
+    assert_eq!(geometry::boundary_points(&corners), 8);
+    assert_eq!(geometry::double_area(&corners), 8);
+    assert_eq!(geometry::interior_points(&corners), Some(1));
+}
+
+#[test]
+fn degenerate_polygons_have_no_interior() {
+    assert_eq!(geometry::interior_points(&[]), None);
+    assert_eq!(
+        geometry::interior_points(&[Point::new(0, 0), Point::new(4, 0)]),
+        None
+    );
+    let collinear = [Point::new(0, 0), Point::new(2, 2), Point::new(5, 5)];
+    assert_eq!(geometry::double_area(&collinear), 0);
+    assert_eq!(geometry::interior_points(&collinear), None);
+}
+
+#[test]
+fn locate_points_in_the_enclosed_example() {
+    let polygon = enclosed();
+    // An `I` tile, an `O` tile in the gap between the two lobes, a loop tile
+    // and the outside corner.
+    assert!(geometry::contains(&polygon, &Point::new(2, 6)));
+    assert_eq!(
+        geometry::locate(&polygon, &Point::new(5, 4)),
+        geometry::Containment::Outside
+    );
+    assert_eq!(
+        geometry::locate(&polygon, &Point::new(1, 1)),
+        geometry::Containment::Boundary
+    );
+    assert!(!geometry::contains(&polygon, &Point::new(0, 0)));
+}
+
+#[test]
+fn pair_sums() {
+    let points = [Point::new(0, 0), Point::new(3, 4), Point::new(-1, 2)];
+    let manhattan: u64 = [(0, 1), (0, 2), (1, 2)]
+        .iter()
+        .map(|(a, b)| points[*a].manhattan(&points[*b]))
+        .sum();
+    let chebyshev: u64 = [(0, 1), (0, 2), (1, 2)]
+        .iter()
+        .map(|(a, b)| points[*a].chebyshev(&points[*b]))
+        .sum();
+    assert_eq!(geometry::manhattan_pair_sum(&points), manhattan);
+    assert_eq!(geometry::chebyshev_pair_sum(&points), chebyshev);
+}
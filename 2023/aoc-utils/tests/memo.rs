@@ -0,0 +1,99 @@
+use aoc_utils::memo::{CacheStats, Memo};
+
+fn fill(memo: &mut Memo<u32, u32>, keys: &[u32]) {
+    for key in keys {
+        memo.insert(*key, key * 10);
+    }
+}
+
+#[test]
+fn counts_hits_and_misses() {
+    let mut memo = Memo::new();
+    let mut calls = 0;
+    for key in [1, 2, 1, 1, 3] {
+        memo.get_or_compute(&key, |_, key| {
+            calls += 1;
+            key * 2
+        });
+    }
+    assert_eq!(calls, 3);
+    assert_eq!(
+        memo.stats(),
+        CacheStats {
+            hits: 2,
+            misses: 3,
+            evictions: 0,
+            len: 3
+        }
+    );
+    assert_eq!(memo.get(&2), Some(&4));
+}
+
+#[test]
+fn borrowed_keys_only_allocate_on_a_miss() {
+    let mut memo: Memo<Vec<i64>, i64> = Memo::new();
+    let sum = |_: &mut Memo<Vec<i64>, i64>, key: &[i64]| key.iter().sum();
+    assert_eq!(memo.get_or_compute(&[1, 2, 3][..], sum), 6);
+    assert_eq!(memo.get_or_compute(&[1, 2, 3][..], sum), 6);
+    assert_eq!(memo.get(&[1, 2, 3][..]), Some(&6));
+    assert_eq!(memo.stats().hits, 1);
+}
+
+#[test]
+fn bounded_cache_evicts_the_oldest_insertion() {
+    let mut memo = Memo::bounded(2);
+    fill(&mut memo, &[1, 2, 3]);
+    assert_eq!(memo.get(&1), None);
+    assert_eq!((memo.get(&2), memo.get(&3)), (Some(&20), Some(&30)));
+    // A hit does not refresh an entry; eviction is by insertion order.
+    assert_eq!(memo.get_or_compute(&2, |_, _| unreachable!()), 20);
+    fill(&mut memo, &[4]);
+    assert_eq!(memo.get(&2), None);
+    assert_eq!(memo.stats().evictions, 2);
+    assert_eq!(memo.len(), 2);
+}
+
+#[test]
+fn reinserting_a_key_replaces_its_value_without_evicting() {
+    let mut memo = Memo::bounded(2);
+    fill(&mut memo, &[1, 2]);
+    memo.insert(1, 100);
+    assert_eq!(memo.get(&1), Some(&100));
+    assert_eq!(memo.stats().evictions, 0);
+    // The key keeps its original place in line.
+    fill(&mut memo, &[3]);
+    assert_eq!(memo.get(&1), None);
+    assert_eq!(memo.get(&2), Some(&20));
+}
+
+#[test]
+fn zero_capacity_stores_nothing() {
+    let mut memo = Memo::bounded(0);
+    fill(&mut memo, &[1, 2]);
+    assert!(memo.is_empty());
+    assert_eq!(memo.get_or_compute(&1, |_, key| key + 1), 2);
+    assert_eq!(memo.get_or_compute(&1, |_, key| key + 1), 2);
+    assert_eq!(
+        memo.stats(),
+        CacheStats {
+            hits: 0,
+            misses: 2,
+            evictions: 0,
+            len: 0
+        }
+    );
+}
+
+#[test]
+fn clear_drops_entries_and_stats() {
+    let mut memo = Memo::bounded(2);
+    fill(&mut memo, &[1, 2, 3]);
+    memo.get_or_compute(&3, |_, _| unreachable!());
+    memo.clear();
+    assert!(memo.is_empty());
+    assert_eq!(memo.stats(), CacheStats::default());
+    // The eviction queue is cleared too, so a fresh fill evicts nothing.
+    fill(&mut memo, &[5, 6]);
+    assert_eq!(memo.stats().evictions, 0);
+    assert_eq!(memo.len(), 2);
+}
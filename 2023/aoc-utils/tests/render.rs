@@ -0,0 +1,137 @@
+use aoc_utils::{
+    bytes::Grid,
+    render::{Color, Frame, Renderer, Style, Viewport},
+};
+
+fn view(rows: &[&str]) -> Vec<Vec<char>> {
+    rows.iter().map(|row| row.chars().collect()).collect()
+}
+
+#[test]
+fn unstyled_cells_have_no_escapes() {
+    let frame = Renderer::new().render(&view(&["ab", "cd"]));
+    assert_eq!(frame.lines, ["ab", "cd"]);
+    assert_eq!(frame.width, 2);
+    let grid = Grid::new(b"ab\r\ncd\r\n");
+    assert_eq!(Renderer::new().render(&grid).lines, ["ab", "cd"]);
+}
+
+#[test]
+fn equal_styles_share_one_escape_run() {
+    let red = Style::fg(Color::Red);
+    let frame = Renderer::new()
+        .highlight_all([(0, 0), (0, 1)], red)
+        .highlight(1, 0, red)
+        .highlight(1, 1, Style::fg(Color::Green))
+        .render(&view(&["abc", "def"]));
+    assert_eq!(
+        frame.lines,
+        ["\x1b[31mab\x1b[0mc", "\x1b[31md\x1b[0m\x1b[32me\x1b[0mf"]
+    );
+    assert_eq!(frame.width, 3);
+}
+
+#[test]
+fn styles_merge_into_one_escape() {
+    let style = Style::fg(Color::Red)
+        .bold()
+        .merge(Style::bg(Color::Rgb(1, 2, 3)));
+    let frame = Renderer::new().highlight(0, 0, style).render(&view(&["a"]));
+    assert_eq!(frame.lines, ["\x1b[1;31;48;2;1;2;3ma\x1b[0m"]);
+    // The highlight's colour wins over the glyph's.
+    let frame = Renderer::new()
+        .style('a', Style::fg(Color::Green))
+        .highlight(0, 0, Style::fg(Color::BrightBlack))
+        .render(&view(&["a"]));
+    assert_eq!(frame.lines, ["\x1b[90ma\x1b[0m"]);
+}
+
+#[test]
+fn glyphs_are_styled_by_their_raw_character() {
+    let frame = Renderer::new()
+        .glyphs([('F', '┌'), ('-', '─')])
+        .style('F', Style::fg(Color::Yellow))
+        .render(&view(&["F-."]));
+    assert_eq!(frame.lines, ["\x1b[33m┌\x1b[0m─."]);
+    assert_eq!(frame.width, 3);
+}
+
+#[test]
+fn plain_drops_every_escape() {
+    let frame = Renderer::new()
+        .highlight(0, 0, Style::fg(Color::Red).bold())
+        .glyph('b', '#')
+        .plain()
+        .render(&view(&["ab"]));
+    assert_eq!(frame.lines, ["a#"]);
+}
+
+#[test]
+fn viewport_is_clamped_to_the_view() {
+    let grid = view(&["abc", "def", "ghi"]);
+    let render = |viewport| Renderer::new().viewport(viewport).render(&grid);
+    let corner = render(Viewport::centered_on(0, 0, 2, 2));
+    assert_eq!(corner.lines, ["ab", "de"]);
+    let overhang = render(Viewport {
+        row: 2,
+        col: 1,
+        height: 5,
+        width: 5,
+    });
+    assert_eq!(overhang.lines, ["hi"]);
+    assert_eq!(overhang.width, 2);
+    let outside = render(Viewport {
+        row: 7,
+        col: 7,
+        height: 2,
+        width: 2,
+    });
+    assert!(outside.lines.is_empty());
+    assert_eq!(outside.width, 0);
+    assert_eq!(render(Viewport::centered_on(1, 1, 1, 1)).lines, ["e"]);
+}
+
+#[test]
+fn side_by_side_pads_by_visible_width() {
+    let left = Frame {
+        lines: vec!["\x1b[31mab\x1b[0m".to_owned(), "c".to_owned()],
+        width: 2,
+    };
+    let right = Frame {
+        lines: vec!["x".to_owned()],
+        width: 1,
+    };
+    let frame = Frame::side_by_side(&[left, right], 2);
+    assert_eq!(frame.lines, ["\x1b[31mab\x1b[0m  x", "c    "]);
+    assert_eq!(frame.width, 5);
+    assert_eq!(Frame::side_by_side(&[], 2).lines, Vec::<String>::new());
+}
+
+#[test]
+fn title_widens_the_frame() {
+    let frame = Renderer::new().render(&view(&["ab"])).with_title("title");
+    assert_eq!(frame.lines, ["title", "ab"]);
+    assert_eq!(frame.width, 5);
+    assert_eq!(frame.to_string(), "title\nab\n");
+}
+
+#[test]
+fn html_escapes_cells_and_title() {
+    let html = Renderer::new()
+        .highlight(0, 1, Style::fg(Color::Red))
+        .render_html(&view(&["<&>\""]), "a<b & \"c\"");
+    assert!(
+        html.contains("<title>a&lt;b &amp; &quot;c&quot;</title>"),
+        "{html}"
+    );
+    assert!(
+        html.contains("&lt;<span style=\"color: #cd0000\">&amp;</span>&gt;&quot;\n"),
+        "{html}"
+    );
+    let plain = Renderer::new()
+        .highlight(0, 1, Style::fg(Color::Red))
+        .plain()
+        .render_html(&view(&["<&"]), "");
+    assert!(!plain.contains("<span"));
+    assert!(plain.contains("<pre>\n&lt;&amp;\n</pre>"));
+}
@@ -0,0 +1,28 @@
+use aoc_utils::rng::Rng;
+
+#[test]
+fn xorshift64_sequence() {
+    let mut rng = Rng::new(1);
+    let values: Vec<u64> = (0..3).map(|_| rng.next_u64()).collect();
+    assert_eq!(
+        values,
+        [1082269761, 1152992998833853505, 11177516664432764457]
+    );
+}
+
+#[test]
+fn zero_seed_does_not_get_stuck() {
+    let mut zero = Rng::new(0);
+    assert_eq!(zero, Rng::new(1));
+    assert_ne!(zero.next_u64(), 0);
+}
+
+#[test]
+fn same_seed_same_values() {
+    let (mut first, mut second) = (Rng::new(0x5eed), Rng::new(0x5eed));
+    for _ in 0..1000 {
+        let value = first.below(7);
+        assert!(value < 7);
+        assert_eq!(value, second.below(7));
+    }
+}
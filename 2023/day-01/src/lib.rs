@@ -0,0 +1,4 @@
+pub mod options;
+pub mod scanner;
+pub mod stream;
+pub mod vocabulary;
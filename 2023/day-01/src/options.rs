@@ -0,0 +1,86 @@
+use std::{io, path::PathBuf};
+
+use crate::{
+    scanner::Scanner,
+    stream::{self, CalibrationReport},
+    vocabulary::Vocabulary,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Included,
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub vocabulary: Vocabulary,
+    pub input: Input,
+    pub threads: usize,
+}
+
+impl Options {
+    /// `[--input PATH|-] [--threads N]`, plus `[--ignore-case] [--language NAME]...
+    /// [--vocabulary PATH]...` when `spelled` is set. Without a language or
+    /// vocabulary file a spelled decoder uses the puzzle's own one..nine.
+    pub fn parse(args: impl IntoIterator<Item = String>, spelled: bool) -> Result<Self, String> {
+        let mut vocabulary = Vocabulary::new();
+        let mut ignore_case = false;
+        let mut input = Input::Included;
+        let mut threads = 1;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--input" => {
+                    input = match value()?.as_str() {
+                        "-" => Input::Stdin,
+                        path => Input::File(PathBuf::from(path)),
+                    }
+                }
+                "--threads" => {
+                    threads = value()?
+                        .parse()
+                        .map_err(|error| format!("--threads: {error}"))?
+                }
+                "--ignore-case" if spelled => ignore_case = true,
+                "--language" if spelled => {
+                    let name = value()?;
+                    vocabulary.extend(&Vocabulary::language(&name).map_err(|error| {
+                        format!(
+                            "{error}, available: {}",
+                            Vocabulary::languages().collect::<Vec<_>>().join(", ")
+                        )
+                    })?);
+                }
+                "--vocabulary" if spelled => {
+                    let path = value()?;
+                    vocabulary.extend(
+                        &Vocabulary::load(&path).map_err(|error| format!("{path}: {error}"))?,
+                    );
+                }
+                other => return Err(format!("Unknown argument {other}")),
+            }
+        }
+        if spelled && vocabulary.words().next().is_none() {
+            vocabulary = Vocabulary::puzzle();
+        }
+
+        Ok(Options {
+            vocabulary: vocabulary.ignore_case(ignore_case),
+            input,
+            threads,
+        })
+    }
+
+    /// Stdin can't be split into chunks, so `threads` only applies to files.
+    pub fn calibrate(&self, included: &str) -> io::Result<CalibrationReport> {
+        let scanner = Scanner::new(self.vocabulary.clone());
+        match &self.input {
+            Input::Included => stream::calibrate_reader(&scanner, included.as_bytes()),
+            Input::Stdin => stream::calibrate_reader(&scanner, io::stdin().lock()),
+            Input::File(path) => stream::calibrate_file(&scanner, path, self.threads),
+        }
+    }
+}
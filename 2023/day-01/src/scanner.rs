@@ -0,0 +1,107 @@
+use crate::vocabulary::Vocabulary;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigitKind {
+    Numeric,
+    Spelled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DigitToken {
+    pub digit: u8,
+    pub position: usize,
+    pub len: usize,
+    pub kind: DigitKind,
+}
+
+/// Finds digits in a calibration line. Every word is tried at every position,
+/// so overlapping words like `eightwo` produce both `8` and `2`.
+#[derive(Debug, Clone, Default)]
+pub struct Scanner {
+    vocabulary: Vocabulary,
+}
+
+impl Scanner {
+    pub fn new(vocabulary: Vocabulary) -> Self {
+        Scanner { vocabulary }
+    }
+
+    pub fn numeric() -> Self {
+        Self::new(Vocabulary::new())
+    }
+
+    pub fn spelled() -> Self {
+        Self::new(Vocabulary::puzzle())
+    }
+
+    pub fn vocabulary(&self) -> &Vocabulary {
+        &self.vocabulary
+    }
+
+    fn numeric_at(line: &str, position: usize) -> Option<DigitToken> {
+        let byte = line.as_bytes()[position];
+        byte.is_ascii_digit().then(|| DigitToken {
+            digit: byte - b'0',
+            position,
+            len: 1,
+            kind: DigitKind::Numeric,
+        })
+    }
+
+    fn spelled_at<'a>(
+        &'a self,
+        line: &'a str,
+        position: usize,
+    ) -> impl Iterator<Item = DigitToken> + 'a {
+        let rest = &line[position..];
+        self.vocabulary.words().filter_map(move |(word, digit)| {
+            self.vocabulary
+                .match_prefix(rest, word)
+                .map(|len| DigitToken {
+                    digit,
+                    position,
+                    len,
+                    kind: DigitKind::Spelled,
+                })
+        })
+    }
+
+    /// Every digit occurrence in order of position.
+    pub fn tokens<'a>(&'a self, line: &'a str) -> impl Iterator<Item = DigitToken> + 'a {
+        line.char_indices().flat_map(move |(position, _)| {
+            Self::numeric_at(line, position)
+                .into_iter()
+                .chain(self.spelled_at(line, position))
+        })
+    }
+
+    pub fn first(&self, line: &str) -> Option<DigitToken> {
+        self.tokens(line).next()
+    }
+
+    /// Scans backwards from the end of the line, so it stops at the last digit
+    /// without visiting the rest.
+    pub fn last(&self, line: &str) -> Option<DigitToken> {
+        line.char_indices().rev().find_map(|(position, char)| {
+            let end = position + char.len_utf8();
+            Self::numeric_at(line, position).or_else(|| {
+                self.vocabulary.words().find_map(|(word, digit)| {
+                    self.vocabulary
+                        .match_suffix(&line[..end], word)
+                        .map(|len| DigitToken {
+                            digit,
+                            position: end - len,
+                            len,
+                            kind: DigitKind::Spelled,
+                        })
+                })
+            })
+        })
+    }
+
+    pub fn calibration_value(&self, line: &str) -> Option<u32> {
+        let first = self.first(line)?;
+        let last = self.last(line)?;
+        Some(u32::from(first.digit) * 10 + u32::from(last.digit))
+    }
+}
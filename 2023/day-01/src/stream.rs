@@ -0,0 +1,123 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, Seek, SeekFrom},
+    ops::AddAssign,
+    path::Path,
+    thread,
+};
+
+use crate::scanner::Scanner;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CalibrationReport {
+    pub lines: u64,
+    pub lines_without_digits: u64,
+    pub total: u64,
+}
+
+impl CalibrationReport {
+    fn record(&mut self, value: Option<u32>) {
+        self.lines += 1;
+        match value {
+            Some(value) => self.total += u64::from(value),
+            None => self.lines_without_digits += 1,
+        }
+    }
+}
+
+impl fmt::Display for CalibrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} lines, {} without digits, total {}",
+            self.lines, self.lines_without_digits, self.total
+        )
+    }
+}
+
+impl AddAssign for CalibrationReport {
+    fn add_assign(&mut self, other: Self) {
+        self.lines += other.lines;
+        self.lines_without_digits += other.lines_without_digits;
+        self.total += other.total;
+    }
+}
+
+fn calibrate_line(scanner: &Scanner, line: &[u8], report: &mut CalibrationReport) {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    report.record(scanner.calibration_value(&String::from_utf8_lossy(line)));
+}
+
+/// Reads one line at a time into a reused buffer, so memory stays at the
+/// length of the longest line no matter how large the input is.
+pub fn calibrate_reader(
+    scanner: &Scanner,
+    mut reader: impl BufRead,
+) -> io::Result<CalibrationReport> {
+    let mut report = CalibrationReport::default();
+    let mut line = Vec::new();
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        calibrate_line(scanner, &line, &mut report);
+        line.clear();
+    }
+    Ok(report)
+}
+
+// Handles every line that starts inside `start..end`. A line straddling `start`
+// belongs to the previous chunk, so we skip to the first newline at or after
+// `start - 1`.
+fn calibrate_chunk(
+    scanner: &Scanner,
+    path: &Path,
+    start: u64,
+    end: u64,
+) -> io::Result<CalibrationReport> {
+    let mut file = File::open(path)?;
+    let mut position = start.saturating_sub(1);
+    file.seek(SeekFrom::Start(position))?;
+    let mut reader = BufReader::new(file);
+    let mut report = CalibrationReport::default();
+    let mut line = Vec::new();
+    if start > 0 {
+        position += reader.read_until(b'\n', &mut line)? as u64;
+        line.clear();
+    }
+    while position < end {
+        let read = reader.read_until(b'\n', &mut line)?;
+        if read == 0 {
+            break;
+        }
+        position += read as u64;
+        calibrate_line(scanner, &line, &mut report);
+        line.clear();
+    }
+    Ok(report)
+}
+
+/// Splits the file into `threads` byte ranges and calibrates them in parallel.
+pub fn calibrate_file(
+    scanner: &Scanner,
+    path: impl AsRef<Path>,
+    threads: usize,
+) -> io::Result<CalibrationReport> {
+    let path = path.as_ref();
+    let len = path.metadata()?.len();
+    let threads = threads.max(1) as u64;
+    let chunk = len.div_ceil(threads).max(1);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|index| (index * chunk, ((index + 1) * chunk).min(len)))
+            .filter(|(start, end)| start < end)
+            .map(|(start, end)| scope.spawn(move || calibrate_chunk(scanner, path, start, end)))
+            .collect();
+        handles
+            .into_iter()
+            .try_fold(CalibrationReport::default(), |mut report, handle| {
+                report += handle.join().expect("Calibration thread panicked")?;
+                Ok(report)
+            })
+    })
+}
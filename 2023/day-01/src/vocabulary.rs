@@ -0,0 +1,190 @@
+use std::{fmt, fs, io, path::Path};
+
+#[derive(Debug)]
+pub enum VocabularyError {
+    Io(io::Error),
+    Malformed { line: usize, text: String },
+    NotADigit { line: usize, value: String },
+    UnknownLanguage(String),
+}
+
+impl fmt::Display for VocabularyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VocabularyError::Io(error) => write!(f, "could not read vocabulary: {error}"),
+            VocabularyError::Malformed { line, text } => {
+                write!(f, "line {line}: expected `word digit`, found {text:?}")
+            }
+            VocabularyError::NotADigit { line, value } => {
+                write!(f, "line {line}: {value:?} is not a digit from 0 to 9")
+            }
+            VocabularyError::UnknownLanguage(language) => {
+                write!(f, "no built-in vocabulary for {language:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VocabularyError {}
+
+impl From<io::Error> for VocabularyError {
+    fn from(error: io::Error) -> Self {
+        VocabularyError::Io(error)
+    }
+}
+
+const LANGUAGES: [(&str, &str); 4] = [
+    ("english", include_str!("../vocabularies/english.txt")),
+    ("german", include_str!("../vocabularies/german.txt")),
+    ("french", include_str!("../vocabularies/french.txt")),
+    ("spanish", include_str!("../vocabularies/spanish.txt")),
+];
+
+/// Words the scanner reads as digits. Several words may map to the same digit,
+/// so vocabularies for different languages can be merged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u8)>,
+    ignore_case: bool,
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The words the puzzle itself uses: `one` to `nine`, no `zero`.
+    pub fn puzzle() -> Self {
+        let mut vocabulary = Self::language("english").unwrap();
+        vocabulary.words.retain(|(_, digit)| *digit != 0);
+        vocabulary
+    }
+
+    pub fn language(name: &str) -> Result<Self, VocabularyError> {
+        let (_, table) = LANGUAGES
+            .iter()
+            .find(|(language, _)| language.eq_ignore_ascii_case(name))
+            .ok_or_else(|| VocabularyError::UnknownLanguage(name.to_owned()))?;
+        Self::from_table(table)
+    }
+
+    pub fn languages() -> impl Iterator<Item = &'static str> {
+        LANGUAGES.iter().map(|(language, _)| *language)
+    }
+
+    /// Parses one `word digit` pair per line. Blank lines and lines starting
+    /// with `#` are skipped; a word may contain spaces since the digit is
+    /// taken from the end.
+    pub fn from_table(table: &str) -> Result<Self, VocabularyError> {
+        let mut vocabulary = Self::new();
+        for (index, text) in table.lines().enumerate() {
+            let line = index + 1;
+            let text = text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let (word, value) = text.rsplit_once(char::is_whitespace).ok_or_else(|| {
+                VocabularyError::Malformed {
+                    line,
+                    text: text.to_owned(),
+                }
+            })?;
+            let digit = value
+                .parse::<u8>()
+                .ok()
+                .filter(|digit| *digit <= 9)
+                .ok_or_else(|| VocabularyError::NotADigit {
+                    line,
+                    value: value.to_owned(),
+                })?;
+            vocabulary.insert(word.trim(), digit);
+        }
+        Ok(vocabulary)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, VocabularyError> {
+        Self::from_table(&fs::read_to_string(path)?)
+    }
+
+    pub fn insert(&mut self, word: &str, digit: u8) {
+        let word = if self.ignore_case {
+            word.to_lowercase()
+        } else {
+            word.to_owned()
+        };
+        if !word.is_empty() && !self.words.contains(&(word.clone(), digit)) {
+            self.words.push((word, digit));
+        }
+    }
+
+    pub fn extend(&mut self, other: &Vocabulary) {
+        other
+            .words
+            .iter()
+            .for_each(|(word, digit)| self.insert(word, *digit));
+    }
+
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        if ignore_case {
+            let words = std::mem::take(&mut self.words);
+            words
+                .iter()
+                .for_each(|(word, digit)| self.insert(word, *digit));
+        }
+        self
+    }
+
+    pub fn is_ignoring_case(&self) -> bool {
+        self.ignore_case
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u8)> {
+        self.words
+            .iter()
+            .map(|(word, digit)| (word.as_str(), *digit))
+    }
+
+    /// Byte length of `word` if `rest` starts with it.
+    pub(crate) fn match_prefix(&self, rest: &str, word: &str) -> Option<usize> {
+        if !self.ignore_case {
+            return rest.starts_with(word).then_some(word.len());
+        }
+        let mut expected = word.chars();
+        let mut consumed = 0;
+        for char in rest.chars() {
+            for lower in char.to_lowercase() {
+                if expected.next() != Some(lower) {
+                    return None;
+                }
+            }
+            consumed += char.len_utf8();
+            if expected.as_str().is_empty() {
+                return Some(consumed);
+            }
+        }
+        None
+    }
+
+    /// Byte length of `word` if `head` ends with it.
+    pub(crate) fn match_suffix(&self, head: &str, word: &str) -> Option<usize> {
+        if !self.ignore_case {
+            return head.ends_with(word).then_some(word.len());
+        }
+        let mut expected = word.chars();
+        let mut consumed = 0;
+        for char in head.chars().rev() {
+            let lower: Vec<char> = char.to_lowercase().collect();
+            for lower in lower.iter().rev() {
+                if expected.next_back() != Some(*lower) {
+                    return None;
+                }
+            }
+            consumed += char.len_utf8();
+            if expected.as_str().is_empty() {
+                return Some(consumed);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,50 @@
+use day_1::scanner::Scanner;
+
+fn digits(scanner: &Scanner, line: &str) -> Vec<u8> {
+    scanner.tokens(line).map(|token| token.digit).collect()
+}
+
+#[test]
+fn readme_examples() {
+    let numeric = Scanner::numeric();
+    let values: Vec<Option<u32>> = ["1abc2", "pqr3stu8vwx", "a1b2c3d4e5f", "treb7uchet"]
+        .iter()
+        .map(|line| numeric.calibration_value(line))
+        .collect();
+    assert_eq!(values, vec![Some(12), Some(38), Some(15), Some(77)]);
+
+    let spelled = Scanner::spelled();
+    let total: u32 = [
+        "two1nine",
+        "eightwothree",
+        "abcone2threexyz",
+        "xtwone3four",
+        "4nineeightseven2",
+        "zoneight234",
+        "7pqrstsixteen",
+    ]
+    .iter()
+    .filter_map(|line| spelled.calibration_value(line))
+    .sum();
+    assert_eq!(total, 281);
+}
+
+#[test]
+fn overlapping_words_yield_both_digits() {
+    let spelled = Scanner::spelled();
+    assert_eq!(digits(&spelled, "twone"), vec![2, 1]);
+    assert_eq!(digits(&spelled, "eightwo"), vec![8, 2]);
+    assert_eq!(digits(&spelled, "oneight"), vec![1, 8]);
+    // The last digit is the word that ends last, not the one found last by
+    // a left-to-right replace.
+    assert_eq!(spelled.calibration_value("twone"), Some(21));
+    assert_eq!(spelled.calibration_value("eightwo"), Some(82));
+    assert_eq!(spelled.calibration_value("xeightwox"), Some(82));
+}
+
+#[test]
+fn lines_without_digits() {
+    assert_eq!(Scanner::numeric().calibration_value("twone"), None);
+    assert_eq!(Scanner::spelled().calibration_value("abc"), None);
+    assert_eq!(Scanner::spelled().calibration_value(""), None);
+}
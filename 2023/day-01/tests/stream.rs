@@ -0,0 +1,60 @@
+use std::{env, fs, process};
+
+use day_1::{
+    scanner::Scanner,
+    stream::{self, CalibrationReport},
+};
+
+// Lines of uneven length, with CRLF endings, digit-free lines and no final
+// newline, so most chunk boundaries land mid-line.
+fn input() -> String {
+    let words = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let mut input = String::new();
+    for index in 0..500 {
+        let word = words[index % words.len()];
+        let line = match index % 5 {
+            0 => format!("{}x{word}y", index % 10),
+            1 => format!("abc{word}def{}{}", index % 7, "z".repeat(index % 13)),
+            2 => "no digits here".to_owned(),
+            3 => format!("{word}ight{}", index % 3),
+            _ => format!("{}{word}", "q".repeat(index % 29)),
+        };
+        input += &line;
+        input += if index % 4 == 0 { "\r\n" } else { "\n" };
+    }
+    input += "7tail";
+    input
+}
+
+#[test]
+fn report_counts() {
+    let report =
+        stream::calibrate_reader(&Scanner::numeric(), "a1b2\nno\n\n3\n".as_bytes()).unwrap();
+    assert_eq!(
+        report,
+        CalibrationReport {
+            lines: 4,
+            lines_without_digits: 2,
+            total: 12 + 33,
+        }
+    );
+    assert_eq!(report.to_string(), "4 lines, 2 without digits, total 45");
+}
+
+#[test]
+fn chunked_threads_match_a_single_reader() {
+    let input = input();
+    let path = env::temp_dir().join(format!("day-1-stream-{}.txt", process::id()));
+    fs::write(&path, &input).unwrap();
+    for scanner in [Scanner::numeric(), Scanner::spelled()] {
+        let expected = stream::calibrate_reader(&scanner, input.as_bytes()).unwrap();
+        assert_eq!(expected.lines, 501);
+        for threads in [1, 2, 3, 4, 7, 16, 64, 5000] {
+            let report = stream::calibrate_file(&scanner, &path, threads).unwrap();
+            assert_eq!(report, expected, "{threads} threads");
+        }
+    }
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,102 @@
+use std::{env, fs, process};
+
+use day_1::{
+    scanner::Scanner,
+    vocabulary::{Vocabulary, VocabularyError},
+};
+
+#[test]
+fn loaded_vocabulary_is_case_sensitive_by_default() {
+    let vocabulary = Vocabulary::from_table("# word digit\nEins 1\nzwei 2\n\nfünf 5\n").unwrap();
+    let scanner = Scanner::new(vocabulary.clone());
+    assert_eq!(scanner.calibration_value("Eins..zwei"), Some(12));
+    assert_eq!(scanner.calibration_value("EINS..ZWEI"), None);
+    assert_eq!(scanner.calibration_value("eins..fünf"), Some(55));
+}
+
+#[test]
+fn ignore_case_lowercases_words_and_lines() {
+    let vocabulary = Vocabulary::from_table("Eins 1\nzwei 2\nfünf 5\n")
+        .unwrap()
+        .ignore_case(true);
+    assert!(vocabulary.is_ignoring_case());
+    assert!(vocabulary
+        .words()
+        .all(|(word, _)| word == word.to_lowercase()));
+    let scanner = Scanner::new(vocabulary);
+    assert_eq!(scanner.calibration_value("EINS..zWeI"), Some(12));
+    // Non-ASCII letters fold too, and the scan from the end agrees.
+    assert_eq!(scanner.calibration_value("xFÜNFx"), Some(55));
+    assert_eq!(scanner.last("zweiFÜNF").map(|token| token.len), Some(5));
+}
+
+#[test]
+fn merged_vocabularies() {
+    let mut vocabulary = Vocabulary::puzzle();
+    vocabulary.extend(&Vocabulary::language("german").unwrap());
+    let scanner = Scanner::new(vocabulary);
+    assert_eq!(scanner.calibration_value("dreixtwo"), Some(32));
+    assert!(Vocabulary::language("klingon").is_err());
+}
+
+#[test]
+fn merging_skips_words_already_known() {
+    let mut vocabulary = Vocabulary::puzzle();
+    let before = vocabulary.words().count();
+    vocabulary.extend(&Vocabulary::puzzle());
+    assert_eq!(vocabulary.words().count(), before);
+    vocabulary.insert("uno", 1);
+    vocabulary.insert("", 7);
+    assert_eq!(vocabulary.words().count(), before + 1);
+}
+
+#[test]
+fn tables_allow_comments_and_spaces_in_words() {
+    let vocabulary = Vocabulary::from_table("# word digit\n\n  forty two 4  \nzero 0\n").unwrap();
+    let words: Vec<(&str, u8)> = vocabulary.words().collect();
+    assert_eq!(words, [("forty two", 4), ("zero", 0)]);
+}
+
+#[test]
+fn malformed_tables_give_the_line() {
+    let error = Vocabulary::from_table("one 1\n\ntwo\n").unwrap_err();
+    assert!(matches!(
+        &error,
+        VocabularyError::Malformed { line: 3, text } if text == "two"
+    ));
+    assert_eq!(
+        error.to_string(),
+        "line 3: expected `word digit`, found \"two\""
+    );
+    let error = Vocabulary::from_table("one 1\nten 10\n").unwrap_err();
+    assert!(matches!(
+        &error,
+        VocabularyError::NotADigit { line: 2, value } if value == "10"
+    ));
+}
+
+#[test]
+fn built_in_languages_and_files() {
+    assert_eq!(
+        Vocabulary::languages().collect::<Vec<_>>(),
+        ["english", "german", "french", "spanish"]
+    );
+    assert!(Vocabulary::language("GERMAN").is_ok());
+    assert!(matches!(
+        Vocabulary::language("klingon"),
+        Err(VocabularyError::UnknownLanguage(language)) if language == "klingon"
+    ));
+
+    let path = env::temp_dir().join(format!("day-1-vocabulary-{}.txt", process::id()));
+    fs::write(&path, "uno 1\ndos 2\n").unwrap();
+    let loaded = Vocabulary::load(&path);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(
+        loaded.unwrap(),
+        Vocabulary::from_table("uno 1\ndos 2").unwrap()
+    );
+    assert!(matches!(
+        Vocabulary::load(&path),
+        Err(VocabularyError::Io(_))
+    ));
+}
@@ -0,0 +1,130 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+#[derive(Debug)]
+pub enum BagError {
+    Io(io::Error),
+    Malformed { line: usize, text: String },
+}
+
+impl fmt::Display for BagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BagError::Io(error) => write!(f, "could not read bag: {error}"),
+            BagError::Malformed { line, text } => {
+                write!(f, "line {line}: expected `color count`, found {text:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BagError {}
+
+impl From<io::Error> for BagError {
+    fn from(error: io::Error) -> Self {
+        BagError::Io(error)
+    }
+}
+
+/// How many cubes of each color the bag holds. A color the bag doesn't
+/// mention holds zero cubes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bag {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Bag {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The bag from the puzzle: 12 red, 13 green and 14 blue cubes.
+    pub fn puzzle() -> Self {
+        Bag::new()
+            .with("red", 12)
+            .with("green", 13)
+            .with("blue", 14)
+    }
+
+    pub fn with(mut self, color: &str, count: u32) -> Self {
+        self.set(color, count);
+        self
+    }
+
+    pub fn set(&mut self, color: &str, count: u32) {
+        self.cubes.insert(color.to_owned(), count);
+    }
+
+    /// Raises `color` to at least `count`.
+    pub fn raise(&mut self, color: &str, count: u32) {
+        let cubes = self.cubes.entry(color.to_owned()).or_default();
+        *cubes = (*cubes).max(count);
+    }
+
+    pub fn get(&self, color: &str) -> u32 {
+        self.cubes.get(color).copied().unwrap_or_default()
+    }
+
+    pub fn allows(&self, color: &str, count: u32) -> bool {
+        count <= self.get(color)
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.cubes.keys().map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.cubes
+            .iter()
+            .map(|(color, count)| (color.as_str(), *count))
+    }
+
+    /// Product of the cube counts over `colors`, so a color missing from the bag
+    /// makes the power zero.
+    pub fn power<'a>(&self, colors: impl IntoIterator<Item = &'a str>) -> u64 {
+        colors
+            .into_iter()
+            .map(|color| u64::from(self.get(color)))
+            .product()
+    }
+
+    /// Parses `color count` or `color=count`.
+    pub fn parse_entry(text: &str) -> Option<(&str, u32)> {
+        let (color, count) = text
+            .split_once('=')
+            .or_else(|| text.trim().rsplit_once(char::is_whitespace))?;
+        let color = color.trim();
+        let count = count.trim().parse().ok()?;
+        (!color.is_empty()).then_some((color, count))
+    }
+
+    /// One entry per line; blank lines and `#` comments are skipped.
+    pub fn from_table(table: &str) -> Result<Self, BagError> {
+        let mut bag = Bag::new();
+        for (index, text) in table.lines().enumerate() {
+            let text = text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let (color, count) = Self::parse_entry(text).ok_or_else(|| BagError::Malformed {
+                line: index + 1,
+                text: text.to_owned(),
+            })?;
+            bag.set(color, count);
+        }
+        Ok(bag)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BagError> {
+        Self::from_table(&fs::read_to_string(path)?)
+    }
+}
+
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries: Vec<String> = self
+            .iter()
+            .map(|(color, count)| format!("{count} {color}"))
+            .collect();
+        write!(f, "{}", entries.join(", "))
+    }
+}
@@ -1,65 +1,94 @@
-struct GameSettings {
-    max_red: i32,
-    max_blue: i32,
-    max_green: i32,
+use std::{env, fs, process};
+
+use day_2::{bag::Bag, explain, game::colors, palette::Palette};
+
+struct Args {
+    bag: Bag,
+    palette: Palette,
+    explain: bool,
+    csv: Option<String>,
 }
 
-impl GameSettings {
-    fn check_color(self: &Self, color: &str, size: i32) -> bool {
-        match color {
-            "blue" => size <= self.max_blue,
-            "red" => size <= self.max_red,
-            "green" => size <= self.max_green,
-            _ => false,
+// challenge1 [--explain] [--csv PATH|-] [--bag-file PATH] [--colors LIST] [COLOR=COUNT]...
+// Without a bag the puzzle's 12 red, 13 green, 14 blue bag is used. Games may
+// draw any color unless `--colors` lists the allowed ones. With `--csv -` the
+// answer goes to stderr.
+fn parse_args() -> Result<Args, String> {
+    let mut bag = None;
+    let mut palette = Palette::any();
+    let mut explain = false;
+    let mut csv = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--explain" => explain = true,
+            "--csv" => csv = Some(args.next().ok_or("--csv needs a path")?),
+            "--colors" => palette = Palette::only(&args.next().ok_or("--colors needs a list")?)?,
+            "--bag-file" => {
+                let path = args.next().ok_or("--bag-file needs a path")?;
+                let loaded = Bag::load(&path).map_err(|error| format!("{path}: {error}"))?;
+                let bag = bag.get_or_insert_with(Bag::new);
+                loaded
+                    .iter()
+                    .for_each(|(color, count)| bag.set(color, count));
+            }
+            _ => {
+                let (color, count) =
+                    Bag::parse_entry(&arg).ok_or(format!("Expected COLOR=COUNT, found {arg}"))?;
+                bag.get_or_insert_with(Bag::new).set(color, count);
+            }
         }
     }
+    Ok(Args {
+        bag: bag.unwrap_or_else(Bag::puzzle),
+        palette,
+        explain,
+        csv,
+    })
 }
 
 fn main() {
-    let game_settings = GameSettings {
-        max_red: 12,
-        max_blue: 14,
-        max_green: 13,
-    };
+    let args = parse_args().unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(2)
+    });
 
     let data = include_str!("./input1.txt");
-    let result: i32 = data
-        .split('\n')
-        .take_while(|row| row.to_string().len() > 0)
-        .map(|game| {
-            let rounds = game.split(':').last().unwrap().split(';');
-            let valid_game = rounds.fold(true, |acc, round| {
-                if !acc {
-                    return acc;
-                }
-                let turns = round.split(',');
-                let valid_round = turns.fold(true, |acc, turn| {
-                    if !acc {
-                        return acc;
-                    }
-                    let pieces = turn.split(' ');
-                    let size: i32 = pieces.clone().take(2).last().unwrap().parse().unwrap();
-                    let color = pieces.last().unwrap();
-
-                    game_settings.check_color(color, size)
-                });
-                valid_round
-            });
+    let games = args
+        .palette
+        .parse_games(data)
+        .unwrap_or_else(|error| panic!("Should parse games: {error}"));
 
-            if valid_game {
-                return game
-                    .split(':')
-                    .next()
-                    .unwrap()
-                    .split(' ')
-                    .last()
-                    .unwrap()
-                    .parse::<i32>()
-                    .unwrap();
+    if args.explain || args.csv.is_some() {
+        let explanations = explain::explain(&games, &args.bag);
+        if args.explain {
+            explanations
+                .iter()
+                .filter(|explanation| !explanation.is_possible())
+                .for_each(|explanation| println!("{explanation}"));
+        }
+        if let Some(path) = &args.csv {
+            let mut colors = colors(&games);
+            colors.extend(args.bag.colors());
+            let csv = explain::to_csv(&explanations, colors);
+            if path == "-" {
+                print!("{csv}");
+            } else {
+                fs::write(path, csv).unwrap_or_else(|error| panic!("{path}: {error}"));
             }
-            0
-        })
+        }
+    }
+
+    let result: u32 = games
+        .iter()
+        .filter(|game| game.is_possible(&args.bag))
+        .map(|game| game.id)
         .sum();
 
-    println!("{}", result)
+    // Keep stdout to the CSV when it is written there.
+    if args.csv.as_deref() == Some("-") {
+        eprintln!("{}", result)
+    } else {
+        println!("{}", result)
+    }
 }
@@ -1,41 +1,41 @@
-use std::cmp;
+use std::{env, process};
 
-fn main() {
-    let data = include_str!("./input2.txt");
-    let result: i32 = data
-        .split('\n')
-        .take_while(|row| row.to_string().len() > 0)
-        .map(|game| {
-            let rounds = game.split(':').last().unwrap().split(';');
-            let game_max = rounds.fold((0, 0, 0), |acc, round| {
-                let turns = round.split(',');
-                let round_max = turns.fold((0, 0, 0), |acc, turn| {
-                    let pieces = turn.split(' ');
-                    let size: i32 = pieces.clone().take(2).last().unwrap().parse().unwrap();
-                    let color = pieces.last().unwrap();
+use day_2::{game::colors, palette::Palette};
+
+// challenge2 [--colors LIST] [COLOR]...
+// Powers are taken over the given colors, or every color the games draw.
+// Games may draw any color unless `--colors` lists the allowed ones.
+fn parse_args() -> Result<(Palette, Vec<String>), String> {
+    let mut palette = Palette::any();
+    let mut requested = vec![];
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--colors" => palette = Palette::only(&args.next().ok_or("--colors needs a list")?)?,
+            _ => requested.push(arg),
+        }
+    }
+    Ok((palette, requested))
+}
 
-                    match color {
-                        "red" => {
-                            return (size, acc.1, acc.2);
-                        }
-                        "green" => {
-                            return (acc.0, size, acc.2);
-                        }
-                        "blue" => {
-                            return (acc.0, acc.1, size);
-                        }
-                        _ => acc,
-                    }
-                });
-                (
-                    cmp::max(round_max.0, acc.0),
-                    cmp::max(round_max.1, acc.1),
-                    cmp::max(round_max.2, acc.2),
-                )
-            });
+fn main() {
+    let (palette, requested) = parse_args().unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(2)
+    });
 
-            game_max.0 * game_max.1 * game_max.2
-        })
+    let data = include_str!("./input2.txt");
+    let games = palette
+        .parse_games(data)
+        .unwrap_or_else(|error| panic!("Should parse games: {error}"));
+    let colors: Vec<&str> = if requested.is_empty() {
+        colors(&games).into_iter().collect()
+    } else {
+        requested.iter().map(String::as_str).collect()
+    };
+    let result: u64 = games
+        .iter()
+        .map(|game| game.power(colors.iter().copied()))
         .sum();
 
     println!("{}", result)
@@ -0,0 +1,156 @@
+use std::fmt;
+
+use crate::{bag::Bag, game::Game};
+
+/// A draw that took more cubes of a color than the bag holds. `round` and
+/// `draw` count from 1, as they read in the input line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub round: usize,
+    pub draw: usize,
+    pub color: String,
+    pub count: u32,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub game: u32,
+    pub violations: Vec<Violation>,
+    pub minimum_bag: Bag,
+}
+
+impl Explanation {
+    pub fn new(game: &Game, bag: &Bag) -> Self {
+        let violations = game
+            .rounds
+            .iter()
+            .enumerate()
+            .flat_map(|(round_index, round)| {
+                round
+                    .draws
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, draw)| !bag.allows(&draw.color, draw.count))
+                    .map(move |(draw_index, draw)| Violation {
+                        round: round_index + 1,
+                        draw: draw_index + 1,
+                        color: draw.color.clone(),
+                        count: draw.count,
+                        limit: bag.get(&draw.color),
+                    })
+            })
+            .collect();
+
+        Explanation {
+            game: game.id,
+            violations,
+            minimum_bag: game.minimum_bag(),
+        }
+    }
+
+    pub fn is_possible(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_possible() {
+            return write!(
+                f,
+                "Game {}: possible, needs {}",
+                self.game, self.minimum_bag
+            );
+        }
+        writeln!(
+            f,
+            "Game {}: impossible, needs {}",
+            self.game, self.minimum_bag
+        )?;
+        for (index, violation) in self.violations.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "  round {} draw {}: {} {} but the bag holds {}",
+                violation.round, violation.draw, violation.count, violation.color, violation.limit
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub fn explain(games: &[Game], bag: &Bag) -> Vec<Explanation> {
+    games
+        .iter()
+        .map(|game| Explanation::new(game, bag))
+        .collect()
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+/// One row per violation, or a single row with empty violation columns for a
+/// possible game. The minimum bag is spread over one `min_<color>` column per
+/// color in `colors`.
+pub fn to_csv<'a>(
+    explanations: &[Explanation],
+    colors: impl IntoIterator<Item = &'a str>,
+) -> String {
+    let colors: Vec<&str> = colors.into_iter().collect();
+    let mut header = vec![
+        "game".to_owned(),
+        "possible".to_owned(),
+        "round".to_owned(),
+        "draw".to_owned(),
+        "color".to_owned(),
+        "count".to_owned(),
+        "limit".to_owned(),
+    ];
+    header.extend(
+        colors
+            .iter()
+            .map(|color| csv_field(&format!("min_{color}"))),
+    );
+
+    let mut csv = header.join(",") + "\n";
+    for explanation in explanations {
+        let minimum_bag: Vec<String> = colors
+            .iter()
+            .map(|color| explanation.minimum_bag.get(color).to_string())
+            .collect();
+        let mut rows: Vec<Vec<String>> = explanation
+            .violations
+            .iter()
+            .map(|violation| {
+                vec![
+                    violation.round.to_string(),
+                    violation.draw.to_string(),
+                    csv_field(&violation.color),
+                    violation.count.to_string(),
+                    violation.limit.to_string(),
+                ]
+            })
+            .collect();
+        if rows.is_empty() {
+            rows.push(vec![String::new(); 5]);
+        }
+        for row in rows {
+            let mut fields = vec![
+                explanation.game.to_string(),
+                explanation.is_possible().to_string(),
+            ];
+            fields.extend(row);
+            fields.extend(minimum_bag.iter().cloned());
+            csv += &(fields.join(",") + "\n");
+        }
+    }
+    csv
+}
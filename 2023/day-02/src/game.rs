@@ -0,0 +1,212 @@
+use std::{collections::BTreeSet, fmt};
+
+use nom::{
+    bytes::complete::{tag, take_till1},
+    character::complete::{alpha1, line_ending, multispace0, space1, u32},
+    error::{ErrorKind, ParseError},
+    multi::separated_list1,
+    sequence::{delimited, preceded},
+    IResult,
+};
+
+use crate::bag::Bag;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Draw {
+    pub color: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub draws: Vec<Draw>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+impl Game {
+    pub fn draws(&self) -> impl Iterator<Item = &Draw> {
+        self.rounds.iter().flat_map(|round| round.draws.iter())
+    }
+
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.draws().all(|draw| bag.allows(&draw.color, draw.count))
+    }
+
+    /// Fewest cubes of each color that make this game possible.
+    pub fn minimum_bag(&self) -> Bag {
+        self.draws().fold(Bag::new(), |mut bag, draw| {
+            bag.raise(&draw.color, draw.count);
+            bag
+        })
+    }
+
+    /// Power of the minimum bag over `colors`; a color this game never draws
+    /// counts as zero cubes.
+    pub fn power<'a>(&self, colors: impl IntoIterator<Item = &'a str>) -> u64 {
+        self.minimum_bag().power(colors)
+    }
+}
+
+/// Every color drawn in any game.
+pub fn colors(games: &[Game]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(|game| game.draws())
+        .map(|draw| draw.color.as_str())
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameErrorKind {
+    UnknownColor(String),
+    MalformedCount(String),
+    Syntax(ErrorKind),
+}
+
+/// nom error that keeps the unparsed input, so the offset can be turned into a
+/// line and column once parsing has failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameError<'a> {
+    pub input: &'a str,
+    pub kind: GameErrorKind,
+}
+
+impl<'a> ParseError<&'a str> for GameError<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        GameError {
+            input,
+            kind: GameErrorKind::Syntax(kind),
+        }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionedError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: GameErrorKind,
+}
+
+impl PositionedError {
+    fn new(source: &str, error: GameError<'_>) -> Self {
+        let offset = source.len() - error.input.len();
+        let before = &source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |index| index + 1) + 1;
+        PositionedError {
+            line,
+            column,
+            kind: error.kind,
+        }
+    }
+}
+
+impl fmt::Display for PositionedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.kind {
+            GameErrorKind::UnknownColor(color) => write!(f, "unknown color {color:?}"),
+            GameErrorKind::MalformedCount(count) => write!(f, "malformed count {count:?}"),
+            GameErrorKind::Syntax(kind) => write!(f, "unexpected input ({kind:?})"),
+        }
+    }
+}
+
+impl std::error::Error for PositionedError {}
+
+type GameResult<'a, T> = IResult<&'a str, T, GameError<'a>>;
+
+fn parse_count(input: &str) -> GameResult<'_, u32> {
+    let (rest, count) =
+        take_till1(|char: char| char.is_whitespace() || char == ',' || char == ';')(input)?;
+    match count.parse() {
+        Ok(count) => Ok((rest, count)),
+        Err(_) => Err(nom::Err::Failure(GameError {
+            input,
+            kind: GameErrorKind::MalformedCount(count.to_owned()),
+        })),
+    }
+}
+
+fn parse_color<'a>(palette: Option<&'a [&'a str]>) -> impl Fn(&'a str) -> GameResult<'a, String> {
+    move |input| {
+        let (rest, color) = alpha1(input)?;
+        match palette {
+            Some(palette) if !palette.contains(&color) => Err(nom::Err::Failure(GameError {
+                input,
+                kind: GameErrorKind::UnknownColor(color.to_owned()),
+            })),
+            _ => Ok((rest, color.to_owned())),
+        }
+    }
+}
+
+fn parse_draw<'a>(palette: Option<&'a [&'a str]>) -> impl Fn(&'a str) -> GameResult<'a, Draw> {
+    move |input| {
+        let (input, count) = parse_count(input)?;
+        let (input, color) = preceded(space1, parse_color(palette))(input)?;
+        Ok((input, Draw { color, count }))
+    }
+}
+
+fn parse_round<'a>(palette: Option<&'a [&'a str]>) -> impl Fn(&'a str) -> GameResult<'a, Round> {
+    move |input| {
+        let (input, draws) = separated_list1(tag(", "), parse_draw(palette))(input)?;
+        Ok((input, Round { draws }))
+    }
+}
+
+fn parse_game<'a>(palette: Option<&'a [&'a str]>) -> impl Fn(&'a str) -> GameResult<'a, Game> {
+    move |input| {
+        let (input, id) = delimited(tag("Game "), u32, tag(": "))(input)?;
+        let (input, rounds) = separated_list1(tag("; "), parse_round(palette))(input)?;
+        Ok((input, Game { id, rounds }))
+    }
+}
+
+fn positioned(source: &str, error: nom::Err<GameError<'_>>) -> PositionedError {
+    match error {
+        nom::Err::Error(error) | nom::Err::Failure(error) => PositionedError::new(source, error),
+        nom::Err::Incomplete(_) => unreachable!("complete parsers never return Incomplete"),
+    }
+}
+
+fn parse_games_from<'a>(
+    input: &'a str,
+    palette: Option<&'a [&'a str]>,
+) -> Result<Vec<Game>, PositionedError> {
+    let (rest, games) = separated_list1(line_ending, parse_game(palette))(input)
+        .map_err(|error| positioned(input, error))?;
+    let (rest, _) = multispace0(rest).map_err(|error| positioned(input, error))?;
+    if !rest.is_empty() {
+        // Whatever stopped the list, parsing from there again names the
+        // actual problem instead of a generic trailing-input error.
+        return Err(match parse_game(palette)(rest) {
+            Err(error) => positioned(input, error),
+            Ok(_) => PositionedError::new(input, GameError::from_error_kind(rest, ErrorKind::Eof)),
+        });
+    }
+    Ok(games)
+}
+
+/// Accepts any color the input mentions.
+pub fn parse_games(input: &str) -> Result<Vec<Game>, PositionedError> {
+    parse_games_from(input, None)
+}
+
+/// Fails on the first draw whose color isn't in `palette`.
+pub fn parse_games_with_palette<'a>(
+    input: &'a str,
+    palette: &'a [&'a str],
+) -> Result<Vec<Game>, PositionedError> {
+    parse_games_from(input, Some(palette))
+}
@@ -0,0 +1,4 @@
+pub mod bag;
+pub mod explain;
+pub mod game;
+pub mod palette;
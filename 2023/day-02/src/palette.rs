@@ -0,0 +1,44 @@
+use crate::game::{parse_games, parse_games_with_palette, Game, PositionedError};
+
+/// The colors games may draw. By default any color the input mentions is
+/// accepted; `--colors` narrows that down so a typo becomes a positioned
+/// parse error instead of a color of its own.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Palette {
+    colors: Option<Vec<String>>,
+}
+
+impl Palette {
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Parses a comma separated list such as `red,green,blue`.
+    pub fn only(list: &str) -> Result<Self, String> {
+        let colors: Vec<String> = list
+            .split(',')
+            .map(|color| color.trim().to_owned())
+            .collect();
+        match colors
+            .iter()
+            .find(|color| color.is_empty() || !color.chars().all(char::is_alphabetic))
+        {
+            Some(color) => Err(format!(
+                "Expected a comma separated color list, found {color:?}"
+            )),
+            None => Ok(Palette {
+                colors: Some(colors),
+            }),
+        }
+    }
+
+    pub fn parse_games(&self, input: &str) -> Result<Vec<Game>, PositionedError> {
+        match &self.colors {
+            Some(colors) => {
+                let palette: Vec<&str> = colors.iter().map(String::as_str).collect();
+                parse_games_with_palette(input, &palette)
+            }
+            None => parse_games(input),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::{env, fs, process};
+
+use day_2::{
+    bag::{Bag, BagError},
+    game::parse_games,
+};
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+#[test]
+fn entries_take_either_separator() {
+    assert_eq!(Bag::parse_entry("red=12"), Some(("red", 12)));
+    assert_eq!(Bag::parse_entry(" blue 14 "), Some(("blue", 14)));
+    assert_eq!(Bag::parse_entry("dark blue 4"), Some(("dark blue", 4)));
+    assert_eq!(Bag::parse_entry("red"), None);
+    assert_eq!(Bag::parse_entry("=3"), None);
+    assert_eq!(Bag::parse_entry("red=many"), None);
+}
+
+#[test]
+fn tables_skip_comments_and_blank_lines() {
+    let bag = Bag::from_table("# the puzzle's bag\nred 12\n\ngreen=13\n  blue 14\n").unwrap();
+    assert_eq!(bag, Bag::puzzle());
+    assert_eq!(bag.to_string(), "14 blue, 13 green, 12 red");
+}
+
+#[test]
+fn malformed_tables_give_the_line() {
+    let error = Bag::from_table("red 12\n# comment\n\nblue\n").unwrap_err();
+    assert!(matches!(
+        &error,
+        BagError::Malformed { line: 4, text } if text == "blue"
+    ));
+    assert_eq!(
+        error.to_string(),
+        "line 4: expected `color count`, found \"blue\""
+    );
+    assert!(matches!(
+        Bag::from_table("red=twelve"),
+        Err(BagError::Malformed { line: 1, .. })
+    ));
+}
+
+#[test]
+fn bags_load_from_files() {
+    let path = env::temp_dir().join(format!("day-2-bag-{}.txt", process::id()));
+    fs::write(&path, "red 1\nyellow 2\n").unwrap();
+    let loaded = Bag::load(&path);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap(), Bag::new().with("red", 1).with("yellow", 2));
+    assert!(matches!(Bag::load(&path), Err(BagError::Io(_))));
+}
+
+#[test]
+fn unmentioned_colors_hold_nothing() {
+    let mut bag = Bag::new().with("red", 3);
+    assert_eq!(bag.get("green"), 0);
+    assert!(bag.allows("green", 0));
+    assert!(!bag.allows("green", 1));
+    bag.raise("red", 2);
+    bag.raise("green", 5);
+    assert_eq!((bag.get("red"), bag.get("green")), (3, 5));
+}
+
+#[test]
+fn power_over_colors_never_drawn_is_zero() {
+    let games = parse_games(SAMPLE).unwrap();
+    let rgb: u64 = games
+        .iter()
+        .map(|game| game.power(["red", "green", "blue"]))
+        .sum();
+    assert_eq!(rgb, 2286);
+    assert_eq!(games[0].power(["red", "green", "blue", "yellow"]), 0);
+    assert_eq!(games[0].power(["red"]), 4);
+    assert_eq!(Bag::puzzle().power([]), 1);
+}
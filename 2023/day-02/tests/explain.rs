@@ -0,0 +1,106 @@
+use day_2::{
+    bag::Bag,
+    explain::{self, Explanation, Violation},
+    game::parse_games,
+};
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+fn explanations() -> Vec<Explanation> {
+    explain::explain(&parse_games(SAMPLE).unwrap(), &Bag::puzzle())
+}
+
+fn violation(round: usize, draw: usize, color: &str, count: u32, limit: u32) -> Violation {
+    Violation {
+        round,
+        draw,
+        color: color.to_owned(),
+        count,
+        limit,
+    }
+}
+
+#[test]
+fn violations_count_rounds_and_draws_from_one() {
+    let explanations = explanations();
+    let possible: Vec<u32> = explanations
+        .iter()
+        .filter(|explanation| explanation.is_possible())
+        .map(|explanation| explanation.game)
+        .collect();
+    assert_eq!(possible, [1, 2, 5]);
+    assert_eq!(explanations[2].violations, [violation(1, 3, "red", 20, 12)]);
+    assert_eq!(
+        explanations[3].violations,
+        [
+            violation(3, 2, "blue", 15, 14),
+            violation(3, 3, "red", 14, 12)
+        ]
+    );
+    assert_eq!(
+        explanations[3].minimum_bag,
+        Bag::new().with("red", 14).with("green", 3).with("blue", 15)
+    );
+}
+
+#[test]
+fn limits_come_from_the_bag() {
+    let games = parse_games("Game 9: 2 yellow, 1 red\n").unwrap();
+    let explanation = Explanation::new(&games[0], &Bag::puzzle());
+    assert_eq!(explanation.violations, [violation(1, 1, "yellow", 2, 0)]);
+    let explanation = Explanation::new(&games[0], &Bag::puzzle().with("yellow", 2));
+    assert!(explanation.is_possible());
+}
+
+#[test]
+fn display_lists_each_violation() {
+    let explanations = explanations();
+    assert_eq!(
+        explanations[0].to_string(),
+        "Game 1: possible, needs 6 blue, 2 green, 4 red"
+    );
+    assert_eq!(
+        explanations[3].to_string(),
+        "Game 4: impossible, needs 15 blue, 3 green, 14 red\n\
+         \x20 round 3 draw 2: 15 blue but the bag holds 14\n\
+         \x20 round 3 draw 3: 14 red but the bag holds 12"
+    );
+}
+
+#[test]
+fn csv_has_a_row_per_violation() {
+    let csv = explain::to_csv(&explanations(), ["red", "green", "blue"]);
+    assert_eq!(
+        csv,
+        "\
+game,possible,round,draw,color,count,limit,min_red,min_green,min_blue
+1,true,,,,,,4,2,6
+2,true,,,,,,1,3,4
+3,false,1,3,red,20,12,20,13,6
+4,false,3,2,blue,15,14,14,3,15
+4,false,3,3,red,14,12,14,3,15
+5,true,,,,,,6,3,2
+"
+    );
+}
+
+#[test]
+fn csv_quotes_awkward_fields() {
+    let explanation = Explanation {
+        game: 1,
+        violations: vec![violation(1, 1, "red, \"dark\"", 3, 2)],
+        minimum_bag: Bag::new(),
+    };
+    let csv = explain::to_csv(&[explanation], ["a,b"]);
+    assert_eq!(
+        csv,
+        "\
+game,possible,round,draw,color,count,limit,\"min_a,b\"
+1,false,1,1,\"red, \"\"dark\"\"\",3,2,0
+"
+    );
+    assert_eq!(
+        explain::to_csv(&[], []),
+        "game,possible,round,draw,color,count,limit\n"
+    );
+}
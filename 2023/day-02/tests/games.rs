@@ -0,0 +1,34 @@
+use day_2::game::{parse_games, parse_games_with_palette, GameErrorKind, PositionedError};
+
+const PALETTE: [&str; 3] = ["red", "green", "blue"];
+
+#[test]
+fn misspelled_color_is_a_positioned_error() {
+    let input = "Game 1: 3 blue, 4 red\nGame 2: 1 red; 3 bleu, 2 green\n";
+    assert_eq!(
+        parse_games_with_palette(input, &PALETTE),
+        Err(PositionedError {
+            line: 2,
+            column: 18,
+            kind: GameErrorKind::UnknownColor("bleu".to_owned()),
+        })
+    );
+    assert_eq!(
+        parse_games_with_palette(input, &PALETTE)
+            .unwrap_err()
+            .to_string(),
+        "2:18: unknown color \"bleu\""
+    );
+    // Without a palette the typo is just another color.
+    assert_eq!(
+        parse_games(input).unwrap()[1].rounds[1].draws[0].color,
+        "bleu"
+    );
+}
+
+#[test]
+fn palette_accepts_its_own_colors() {
+    let games = parse_games_with_palette("Game 7: 1 green; 2 blue, 3 red\n", &PALETTE).unwrap();
+    assert_eq!(games[0].id, 7);
+    assert_eq!(games[0].draws().count(), 3);
+}
@@ -0,0 +1,74 @@
+use day_2::{
+    bag::Bag,
+    game::{colors, GameErrorKind, PositionedError},
+    palette::Palette,
+};
+
+const YELLOW: &str = "\
+Game 1: 3 blue, 4 yellow
+Game 2: 2 red, 1 yellow; 5 blue
+";
+
+#[test]
+fn any_color_in_the_input_is_accepted_by_default() {
+    let games = Palette::any().parse_games(YELLOW).unwrap();
+    assert_eq!(
+        colors(&games).into_iter().collect::<Vec<_>>(),
+        ["blue", "red", "yellow"]
+    );
+}
+
+#[test]
+fn possible_games_with_a_non_rgb_color() {
+    // challenge1: a bag without yellow rules out any game that draws it.
+    let games = Palette::any().parse_games(YELLOW).unwrap();
+    let possible = |bag: &Bag| -> u32 {
+        games
+            .iter()
+            .filter(|game| game.is_possible(bag))
+            .map(|game| game.id)
+            .sum()
+    };
+    assert_eq!(possible(&Bag::puzzle()), 0);
+    assert_eq!(possible(&Bag::puzzle().with("yellow", 1)), 2);
+    assert_eq!(possible(&Bag::puzzle().with("yellow", 4)), 3);
+}
+
+#[test]
+fn powers_over_every_drawn_color() {
+    // challenge2: without requested colors the power spans all of them, so
+    // a game that never draws one of them has power zero.
+    let games = Palette::any().parse_games(YELLOW).unwrap();
+    let drawn = colors(&games);
+    let powers: Vec<u64> = games
+        .iter()
+        .map(|game| game.power(drawn.iter().copied()))
+        .collect();
+    assert_eq!(powers, [0, 2 * 5]);
+    assert_eq!(games[0].power(["blue", "yellow"]), 3 * 4);
+}
+
+#[test]
+fn explicit_colors_reject_the_rest() {
+    let rgb = Palette::only("red,green,blue").unwrap();
+    assert_eq!(
+        rgb.parse_games(YELLOW),
+        Err(PositionedError {
+            line: 1,
+            column: 19,
+            kind: GameErrorKind::UnknownColor("yellow".to_owned()),
+        })
+    );
+    let with_yellow = Palette::only("red, green, blue, yellow").unwrap();
+    assert_eq!(with_yellow.parse_games(YELLOW).unwrap().len(), 2);
+}
+
+#[test]
+fn color_lists_must_name_colors() {
+    assert!(Palette::only("red,,blue").is_err());
+    assert!(Palette::only("").is_err());
+    assert_eq!(
+        Palette::only("red=3"),
+        Err("Expected a comma separated color list, found \"red=3\"".to_owned())
+    );
+}
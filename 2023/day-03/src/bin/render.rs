@@ -0,0 +1,113 @@
+use std::{env, fs, process};
+
+use aoc_utils::render::{Renderer, Viewport};
+use day_3::{gear::GearRule, scheme::EngineScheme, view::SchematicView};
+
+struct Args {
+    input: Option<String>,
+    html: Option<String>,
+    plain: bool,
+    viewport: Option<Viewport>,
+    rule: GearRule,
+}
+
+fn parse_viewport(text: &str) -> Option<Viewport> {
+    let parts: Vec<usize> = text
+        .split(',')
+        .map(|part| part.trim().parse().ok())
+        .collect::<Option<_>>()?;
+    match parts[..] {
+        [row, col, height, width] => Some(Viewport {
+            row,
+            col,
+            height,
+            width,
+        }),
+        _ => None,
+    }
+}
+
+// render [--input PATH] [--html PATH] [--plain] [--viewport ROW,COL,HEIGHT,WIDTH]
+//        [--symbols CHARS] [--adjacent N]
+fn parse_args() -> Result<Args, String> {
+    let mut parsed = Args {
+        input: None,
+        html: None,
+        plain: false,
+        viewport: None,
+        rule: GearRule::default(),
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--input" => parsed.input = Some(value()?),
+            "--html" => parsed.html = Some(value()?),
+            "--plain" => parsed.plain = true,
+            "--viewport" => {
+                let text = value()?;
+                parsed.viewport = Some(
+                    parse_viewport(&text)
+                        .ok_or(format!("Expected ROW,COL,HEIGHT,WIDTH, found {text}"))?,
+                )
+            }
+            "--symbols" => parsed.rule.symbols = value()?.into_bytes(),
+            "--adjacent" => {
+                parsed.rule.adjacent = value()?
+                    .parse()
+                    .map_err(|error| format!("--adjacent: {error}"))?
+            }
+            other => return Err(format!("Unknown argument {other}")),
+        }
+    }
+    Ok(parsed)
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(2)
+    });
+
+    let data = match &args.input {
+        Some(path) => fs::read_to_string(path).unwrap_or_else(|error| panic!("{path}: {error}")),
+        None => include_str!("./input1.txt").to_owned(),
+    };
+    let scheme = EngineScheme::fill_scheme(&data).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1)
+    });
+    let view = SchematicView::new(&scheme, &args.rule);
+
+    let mut renderer = Renderer::new();
+    if let Some(viewport) = args.viewport {
+        renderer = renderer.viewport(viewport);
+    }
+    if args.plain {
+        renderer = renderer.plain();
+    }
+
+    match &args.html {
+        Some(path) => {
+            let html = renderer.render_html(&view, "Engine schematic");
+            fs::write(path, html).unwrap_or_else(|error| panic!("{path}: {error}"));
+        }
+        None => {
+            let legend: Vec<String> = SchematicView::legend()
+                .iter()
+                .map(|(name, style)| {
+                    let swatch = Renderer::new()
+                        .highlight(0, 0, *style)
+                        .render(&vec![vec!['#']]);
+                    if args.plain {
+                        name.to_string()
+                    } else {
+                        format!("{} {name}", swatch.lines[0])
+                    }
+                })
+                .collect();
+            println!("{}", legend.join("  "));
+            print!("{}", renderer.render(&view));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::scheme::{Area, EngineScheme, Number, NumberTooLarge, Symbol, SymbolId};
+
+/// Which symbol cells count as gears: the glyph must be one of `symbols` and
+/// the cell must touch exactly `adjacent` distinct numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRule {
+    pub symbols: Vec<u8>,
+    pub adjacent: usize,
+}
+
+impl Default for GearRule {
+    /// The puzzle's rule: a `*` next to exactly two numbers.
+    fn default() -> Self {
+        GearRule {
+            symbols: vec![b'*'],
+            adjacent: 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gear {
+    pub symbol: Symbol,
+    pub numbers: Vec<Number>,
+}
+
+impl Gear {
+    pub fn ratio(&self) -> u64 {
+        self.numbers.iter().map(|number| number.value).product()
+    }
+}
+
+impl GearRule {
+    pub fn new(symbols: &[u8], adjacent: usize) -> Self {
+        GearRule {
+            symbols: symbols.to_vec(),
+            adjacent,
+        }
+    }
+
+    pub fn gear(&self, scheme: &EngineScheme, symbol: SymbolId) -> Option<Gear> {
+        let glyph = scheme.symbols()[symbol].glyph;
+        if !self.symbols.contains(&glyph) {
+            return None;
+        }
+        let numbers: Vec<Number> = scheme.adjacent_numbers(symbol).copied().collect();
+        (numbers.len() == self.adjacent).then(|| Gear {
+            symbol: scheme.symbols()[symbol],
+            numbers,
+        })
+    }
+
+    /// Every gear in symbol id order, which is row-major until the scheme is
+    /// edited. A number may belong to several gears.
+    pub fn gears<'a>(&'a self, scheme: &'a EngineScheme) -> impl Iterator<Item = Gear> + 'a {
+        (0..scheme.symbols().len()).filter_map(|symbol| self.gear(scheme, symbol))
+    }
+
+    pub fn ratio_sum(&self, scheme: &EngineScheme) -> u64 {
+        self.gears(scheme).map(|gear| gear.ratio()).sum()
+    }
+}
+
+/// The gear-ratio sum under `rule`, kept up to date across cell edits by
+/// re-examining only the symbols in the edit's area.
+#[derive(Debug, Clone)]
+pub struct GearRatios {
+    rule: GearRule,
+    sum: u64,
+}
+
+impl GearRatios {
+    pub fn new(rule: GearRule, scheme: &EngineScheme) -> Self {
+        let sum = rule.ratio_sum(scheme);
+        GearRatios { rule, sum }
+    }
+
+    fn sum_in(&self, scheme: &EngineScheme, area: &Area) -> u64 {
+        scheme
+            .symbols_in(area)
+            .into_iter()
+            .filter_map(|symbol| self.rule.gear(scheme, symbol))
+            .map(|gear| gear.ratio())
+            .sum()
+    }
+
+    /// Applies the edit to `scheme` and adjusts the sum. A rejected edit leaves
+    /// both untouched.
+    pub fn set(
+        &mut self,
+        scheme: &mut EngineScheme,
+        row: usize,
+        col: usize,
+        byte: u8,
+    ) -> Result<(), NumberTooLarge> {
+        let area = scheme.edit_area(row, col);
+        let before = self.sum_in(scheme, &area);
+        scheme.set(row, col, byte)?;
+        self.sum = self.sum - before + self.sum_in(scheme, &area);
+        Ok(())
+    }
+
+    pub fn rule(&self) -> &GearRule {
+        &self.rule
+    }
+
+    pub fn sum(&self) -> u64 {
+        self.sum
+    }
+}
@@ -0,0 +1,3 @@
+pub mod gear;
+pub mod scheme;
+pub mod view;
@@ -0,0 +1,461 @@
+use std::{error, fmt, ops::RangeInclusive};
+
+use aoc_utils::bytes::{self, Grid};
+
+pub type NumberId = usize;
+pub type SymbolId = usize;
+
+/// A run of digits on one row; `end` is the column of the last digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+    pub value: u64,
+}
+
+/// Any cell that is neither a digit nor `.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub row: usize,
+    pub col: usize,
+    pub glyph: u8,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Label {
+    #[default]
+    Empty,
+    Number(NumberId),
+    Symbol(SymbolId),
+}
+
+/// A digit run too long to fit in a `u64`, with the columns of its first and
+/// last digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberTooLarge {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl fmt::Display for NumberTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, columns {}-{}: number does not fit in 64 bits",
+            self.row + 1,
+            self.start + 1,
+            self.end + 1
+        )
+    }
+}
+
+impl error::Error for NumberTooLarge {}
+
+/// The cells an edit can affect: rows and columns are clamped to the schematic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Area {
+    pub rows: RangeInclusive<usize>,
+    pub cols: RangeInclusive<usize>,
+}
+
+/// The schematic together with a label for every cell and the adjacency
+/// between numbers and symbols, all built in a single row-major pass. Ids are
+/// dense; removing a number or symbol moves the last one into its id.
+#[derive(Debug, Clone)]
+pub struct EngineScheme {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+    labels: Vec<Label>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    number_symbols: Vec<Vec<SymbolId>>,
+    symbol_numbers: Vec<Vec<NumberId>>,
+    part_sum: u64,
+}
+
+fn is_symbol(byte: u8) -> bool {
+    !byte.is_ascii_digit() && byte != b'.'
+}
+
+impl EngineScheme {
+    pub fn fill_scheme(data: &str) -> Result<Self, NumberTooLarge> {
+        let grid = Grid::new(data.as_bytes());
+        for (row, cells) in grid.rows().enumerate() {
+            if let Some(Err(overflow)) = bytes::unsigneds(cells).find(Result::is_err) {
+                return Err(NumberTooLarge {
+                    row,
+                    start: overflow.start,
+                    end: overflow.end - 1,
+                });
+            }
+        }
+        let mut scheme = EngineScheme {
+            width: grid.width(),
+            height: grid.height(),
+            cells: grid.rows().flatten().copied().collect(),
+            labels: vec![Label::Empty; grid.width() * grid.height()],
+            numbers: vec![],
+            symbols: vec![],
+            number_symbols: vec![],
+            symbol_numbers: vec![],
+            part_sum: 0,
+        };
+        for row in 0..scheme.height {
+            for col in 0..scheme.width {
+                let label = scheme.label_cell(row, col);
+                scheme.link_earlier(row, col, label);
+            }
+        }
+        scheme.part_sum = scheme.part_numbers().map(|number| number.value).sum();
+        Ok(scheme)
+    }
+
+    // Labels `(row, col)`, extending the number on its left if there is one.
+    // Digit runs are checked to fit in a `u64` before they are labelled.
+    fn label_cell(&mut self, row: usize, col: usize) -> Label {
+        let byte = self.cells[row * self.width + col];
+        let label = if byte.is_ascii_digit() {
+            let digit = u64::from(byte - b'0');
+            match (col > 0).then(|| self.label(row, col - 1)) {
+                Some(Label::Number(id)) => {
+                    let number = &mut self.numbers[id];
+                    number.value = number.value * 10 + digit;
+                    number.end = col;
+                    Label::Number(id)
+                }
+                _ => {
+                    self.numbers.push(Number {
+                        row,
+                        start: col,
+                        end: col,
+                        value: digit,
+                    });
+                    self.number_symbols.push(vec![]);
+                    Label::Number(self.numbers.len() - 1)
+                }
+            }
+        } else if is_symbol(byte) {
+            self.symbols.push(Symbol {
+                row,
+                col,
+                glyph: byte,
+            });
+            self.symbol_numbers.push(vec![]);
+            Label::Symbol(self.symbols.len() - 1)
+        } else {
+            Label::Empty
+        };
+        self.labels[row * self.width + col] = label;
+        label
+    }
+
+    // Every adjacent pair has exactly one cell that comes later in row-major
+    // order, so linking each cell to the neighbours before it sees each pair
+    // once by the end of the scan.
+    fn link_earlier(&mut self, row: usize, col: usize, label: Label) {
+        let mut earlier = vec![];
+        if row > 0 {
+            earlier.extend(
+                (col.saturating_sub(1)..=col + 1)
+                    .filter(|col| *col < self.width)
+                    .map(|col| (row - 1, col)),
+            );
+        }
+        if col > 0 {
+            earlier.push((row, col - 1));
+        }
+        for (neighbour_row, neighbour_col) in earlier {
+            self.link(label, self.label(neighbour_row, neighbour_col));
+        }
+    }
+
+    fn link(&mut self, first: Label, second: Label) {
+        let (number, symbol) = match (first, second) {
+            (Label::Number(number), Label::Symbol(symbol))
+            | (Label::Symbol(symbol), Label::Number(number)) => (number, symbol),
+            _ => return,
+        };
+        if !self.number_symbols[number].contains(&symbol) {
+            self.number_symbols[number].push(symbol);
+            self.symbol_numbers[symbol].push(number);
+        }
+    }
+
+    fn cells_in(&self, area: &Area) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let cols = area.cols.clone();
+        area.rows
+            .clone()
+            .flat_map(move |row| cols.clone().map(move |col| (row, col)))
+    }
+
+    fn numbers_in(&self, area: &Area) -> Vec<NumberId> {
+        let mut numbers = vec![];
+        for (row, col) in self.cells_in(area) {
+            if let Label::Number(number) = self.label(row, col) {
+                if !numbers.contains(&number) {
+                    numbers.push(number);
+                }
+            }
+        }
+        numbers
+    }
+
+    /// Symbols with a cell inside `area`.
+    pub fn symbols_in(&self, area: &Area) -> Vec<SymbolId> {
+        self.cells_in(area)
+            .filter_map(|(row, col)| match self.label(row, col) {
+                Label::Symbol(symbol) => Some(symbol),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // The columns of the digit run through `(row, col)` once it is edited: the
+    // cell itself plus any number touching it on the same row.
+    fn edit_run(&self, row: usize, col: usize) -> RangeInclusive<usize> {
+        let mut start = col;
+        let mut end = col;
+        if let Some(number) = col
+            .checked_sub(1)
+            .and_then(|left| self.number_at(row, left))
+        {
+            start = number.start;
+        }
+        if let Some(number) = self.number_at(row, col + 1) {
+            end = number.end;
+        }
+        if let Some(number) = self.number_at(row, col) {
+            start = start.min(number.start);
+            end = end.max(number.end);
+        }
+        start..=end
+    }
+
+    /// Everything whose labels or adjacency can change when `(row, col)` is
+    /// edited: the digit run through the cell widened by one cell on every
+    /// side. Any number or symbol that gains or loses a neighbour has a cell in
+    /// here, both before and after the edit.
+    pub fn edit_area(&self, row: usize, col: usize) -> Area {
+        let run = self.edit_run(row, col);
+        Area {
+            rows: row.saturating_sub(1)..=(row + 1).min(self.height - 1),
+            cols: run.start().saturating_sub(1)..=(run.end() + 1).min(self.width - 1),
+        }
+    }
+
+    fn part_sum_in(&self, area: &Area) -> u64 {
+        self.numbers_in(area)
+            .into_iter()
+            .filter(|number| self.is_part(*number))
+            .map(|number| self.numbers[number].value)
+            .sum()
+    }
+
+    fn remove_number(&mut self, number: NumberId) {
+        let removed = self.numbers[number];
+        for col in removed.start..=removed.end {
+            self.labels[removed.row * self.width + col] = Label::Empty;
+        }
+        for symbol in &self.number_symbols[number] {
+            self.symbol_numbers[*symbol].retain(|other| *other != number);
+        }
+        let last = self.numbers.len() - 1;
+        self.numbers.swap_remove(number);
+        self.number_symbols.swap_remove(number);
+        if number == last {
+            return;
+        }
+        let moved = self.numbers[number];
+        for col in moved.start..=moved.end {
+            self.labels[moved.row * self.width + col] = Label::Number(number);
+        }
+        for symbol in &self.number_symbols[number] {
+            for other in &mut self.symbol_numbers[*symbol] {
+                if *other == last {
+                    *other = number;
+                }
+            }
+        }
+    }
+
+    fn remove_symbol(&mut self, symbol: SymbolId) {
+        let removed = self.symbols[symbol];
+        self.labels[removed.row * self.width + removed.col] = Label::Empty;
+        for number in &self.symbol_numbers[symbol] {
+            self.number_symbols[*number].retain(|other| *other != symbol);
+        }
+        let last = self.symbols.len() - 1;
+        self.symbols.swap_remove(symbol);
+        self.symbol_numbers.swap_remove(symbol);
+        if symbol == last {
+            return;
+        }
+        let moved = self.symbols[symbol];
+        self.labels[moved.row * self.width + moved.col] = Label::Symbol(symbol);
+        for number in &self.symbol_numbers[symbol] {
+            for other in &mut self.number_symbols[*number] {
+                if *other == last {
+                    *other = symbol;
+                }
+            }
+        }
+    }
+
+    /// Replaces one cell and relabels only the digit run it belongs to, keeping
+    /// the part sum up to date. Returns the area that was re-examined, or
+    /// leaves the scheme untouched if the edit would join digits into a number
+    /// too large for a `u64`.
+    pub fn set(&mut self, row: usize, col: usize, byte: u8) -> Result<Area, NumberTooLarge> {
+        assert!(
+            row < self.height && col < self.width,
+            "({row}, {col}) is outside the schematic"
+        );
+        assert!(
+            byte != b'\n' && byte != b'\r',
+            "cells can't hold line endings"
+        );
+        let run = self.edit_run(row, col);
+        if byte.is_ascii_digit() {
+            let digits: Vec<u8> = run
+                .clone()
+                .map(|run_col| {
+                    if run_col == col {
+                        byte
+                    } else {
+                        self.cells[row * self.width + run_col]
+                    }
+                })
+                .collect();
+            if bytes::parse_unsigned(&digits).is_none() {
+                return Err(NumberTooLarge {
+                    row,
+                    start: *run.start(),
+                    end: *run.end(),
+                });
+            }
+        }
+        let area = self.edit_area(row, col);
+        self.part_sum -= self.part_sum_in(&area);
+
+        for run_col in run.clone() {
+            match self.label(row, run_col) {
+                Label::Number(number) => self.remove_number(number),
+                Label::Symbol(symbol) => self.remove_symbol(symbol),
+                Label::Empty => (),
+            }
+        }
+        self.cells[row * self.width + col] = byte;
+        for run_col in run.clone() {
+            self.label_cell(row, run_col);
+        }
+        for run_col in run {
+            let label = self.label(row, run_col);
+            let neighbours: Vec<(usize, usize)> = self.neighbours(row, run_col).collect();
+            for (neighbour_row, neighbour_col) in neighbours {
+                self.link(label, self.label(neighbour_row, neighbour_col));
+            }
+        }
+
+        self.part_sum += self.part_sum_in(&area);
+        Ok(area)
+    }
+
+    fn neighbours(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (height, width) = (self.height, self.width);
+        (row.saturating_sub(1)..=row + 1)
+            .flat_map(move |neighbour_row| {
+                (col.saturating_sub(1)..=col + 1)
+                    .map(move |neighbour_col| (neighbour_row, neighbour_col))
+            })
+            .filter(move |(neighbour_row, neighbour_col)| {
+                (*neighbour_row, *neighbour_col) != (row, col)
+                    && *neighbour_row < height
+                    && *neighbour_col < width
+            })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<u8> {
+        (row < self.height && col < self.width).then(|| self.cells[row * self.width + col])
+    }
+
+    /// `Label::Empty` outside the schematic.
+    pub fn label(&self, row: usize, col: usize) -> Label {
+        if row < self.height && col < self.width {
+            self.labels[row * self.width + col]
+        } else {
+            Label::Empty
+        }
+    }
+
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    pub fn number_at(&self, row: usize, col: usize) -> Option<&Number> {
+        match self.label(row, col) {
+            Label::Number(id) => Some(&self.numbers[id]),
+            _ => None,
+        }
+    }
+
+    pub fn symbol_at(&self, row: usize, col: usize) -> Option<&Symbol> {
+        match self.label(row, col) {
+            Label::Symbol(id) => Some(&self.symbols[id]),
+            _ => None,
+        }
+    }
+
+    /// Symbols touching the number, in the order they were linked.
+    pub fn adjacent_symbols(&self, number: NumberId) -> impl Iterator<Item = &Symbol> {
+        self.number_symbols[number]
+            .iter()
+            .map(|symbol| &self.symbols[*symbol])
+    }
+
+    /// Distinct numbers touching the symbol, in the order they were linked.
+    pub fn adjacent_numbers(&self, symbol: SymbolId) -> impl Iterator<Item = &Number> {
+        self.symbol_numbers[symbol]
+            .iter()
+            .map(|number| &self.numbers[*number])
+    }
+
+    pub fn is_part(&self, number: NumberId) -> bool {
+        !self.number_symbols[number].is_empty()
+    }
+
+    /// Numbers that touch at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        (0..self.numbers.len())
+            .filter(|number| self.is_part(*number))
+            .map(|number| &self.numbers[number])
+    }
+
+    /// Kept up to date by `set`.
+    pub fn part_sum(&self) -> u64 {
+        self.part_sum
+    }
+}
+
+impl fmt::Display for EngineScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
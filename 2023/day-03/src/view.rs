@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use aoc_utils::render::{Color, GridView, Style};
+
+use crate::{
+    gear::GearRule,
+    scheme::{EngineScheme, Label},
+};
+
+pub const PART_NUMBER: Style = Style {
+    fg: Some(Color::BrightGreen),
+    bg: None,
+    bold: true,
+};
+pub const OTHER_NUMBER: Style = Style {
+    fg: Some(Color::Red),
+    bg: None,
+    bold: false,
+};
+pub const SYMBOL: Style = Style {
+    fg: Some(Color::Yellow),
+    bg: None,
+    bold: false,
+};
+pub const GEAR: Style = Style {
+    fg: Some(Color::BrightWhite),
+    bg: Some(Color::Magenta),
+    bold: true,
+};
+pub const EMPTY: Style = Style {
+    fg: Some(Color::BrightBlack),
+    bg: None,
+    bold: false,
+};
+
+/// Colours each cell by what it is: part number, number that touches no
+/// symbol, symbol, or a symbol that is a gear under `rule`.
+pub struct SchematicView<'a> {
+    scheme: &'a EngineScheme,
+    gears: HashSet<(usize, usize)>,
+}
+
+impl<'a> SchematicView<'a> {
+    pub fn new(scheme: &'a EngineScheme, rule: &GearRule) -> Self {
+        SchematicView {
+            scheme,
+            gears: rule
+                .gears(scheme)
+                .map(|gear| (gear.symbol.row, gear.symbol.col))
+                .collect(),
+        }
+    }
+
+    pub fn legend() -> [(&'static str, Style); 5] {
+        [
+            ("part number", PART_NUMBER),
+            ("other number", OTHER_NUMBER),
+            ("symbol", SYMBOL),
+            ("gear", GEAR),
+            ("empty", EMPTY),
+        ]
+    }
+}
+
+impl GridView for SchematicView<'_> {
+    fn width(&self) -> usize {
+        self.scheme.width()
+    }
+
+    fn height(&self) -> usize {
+        self.scheme.height()
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        char::from(self.scheme.get(row, col).unwrap_or(b' '))
+    }
+
+    fn style(&self, row: usize, col: usize) -> Style {
+        match self.scheme.label(row, col) {
+            Label::Number(number) if self.scheme.is_part(number) => PART_NUMBER,
+            Label::Number(_) => OTHER_NUMBER,
+            Label::Symbol(_) if self.gears.contains(&(row, col)) => GEAR,
+            Label::Symbol(_) => SYMBOL,
+            Label::Empty => EMPTY,
+        }
+    }
+}
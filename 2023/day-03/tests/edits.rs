@@ -0,0 +1,116 @@
+use aoc_utils::rng::Rng;
+use day_3::{
+    gear::{GearRatios, GearRule},
+    scheme::{EngineScheme, NumberTooLarge},
+};
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+const ALPHABET: &[u8] = b"0123456789......*#+";
+
+fn random_scheme(rng: &mut Rng, height: usize, width: usize) -> String {
+    (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| char::from(ALPHABET[rng.below(ALPHABET.len())]))
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+fn check_edits(data: &str, rule: GearRule, rng: &mut Rng, edits: usize) {
+    let mut scheme = EngineScheme::fill_scheme(data).unwrap();
+    let mut ratios = GearRatios::new(rule.clone(), &scheme);
+    for _ in 0..edits {
+        let row = rng.below(scheme.height());
+        let col = rng.below(scheme.width());
+        let byte = ALPHABET[rng.below(ALPHABET.len())];
+        ratios.set(&mut scheme, row, col, byte).unwrap();
+
+        let fresh = EngineScheme::fill_scheme(&scheme.to_string()).unwrap();
+        assert_eq!(
+            scheme.part_sum(),
+            fresh.part_sum(),
+            "after ({row}, {col}) = {}\n{scheme}",
+            char::from(byte)
+        );
+        assert_eq!(
+            scheme.part_sum(),
+            fresh.part_numbers().map(|number| number.value).sum::<u64>()
+        );
+        assert_eq!(
+            ratios.sum(),
+            rule.ratio_sum(&fresh),
+            "after ({row}, {col}) = {}\n{scheme}",
+            char::from(byte)
+        );
+        assert_eq!(ratios.sum(), rule.ratio_sum(&scheme));
+        assert_eq!(scheme.numbers().len(), fresh.numbers().len());
+        assert_eq!(scheme.symbols().len(), fresh.symbols().len());
+    }
+}
+
+#[test]
+fn edits_on_the_sample() {
+    let mut rng = Rng::new(0x5eed);
+    check_edits(SAMPLE, GearRule::default(), &mut rng, 2000);
+}
+
+#[test]
+fn edits_on_random_schemes() {
+    let mut rng = Rng::new(0x0dd_ba11);
+    for _ in 0..50 {
+        let height = 1 + rng.below(8);
+        let width = 1 + rng.below(8);
+        let data = random_scheme(&mut rng, height, width);
+        check_edits(&data, GearRule::default(), &mut rng, 200);
+        check_edits(&data, GearRule::new(b"*#", 1), &mut rng, 200);
+    }
+}
+
+#[test]
+fn edit_joins_and_splits_numbers() {
+    let mut scheme = EngineScheme::fill_scheme("12.34\n..*..\n").unwrap();
+    let mut ratios = GearRatios::new(GearRule::default(), &scheme);
+    assert_eq!((scheme.part_sum(), ratios.sum()), (46, 408));
+
+    ratios.set(&mut scheme, 0, 2, b'5').unwrap();
+    assert_eq!(scheme.numbers().len(), 1);
+    assert_eq!((scheme.part_sum(), ratios.sum()), (12534, 0));
+
+    ratios.set(&mut scheme, 0, 1, b'.').unwrap();
+    assert_eq!((scheme.part_sum(), ratios.sum()), (534, 0));
+
+    ratios.set(&mut scheme, 1, 2, b'.').unwrap();
+    assert_eq!((scheme.part_sum(), ratios.sum()), (0, 0));
+}
+
+#[test]
+fn edits_that_overflow_are_rejected() {
+    let data = "1844674407370955161.5*\n";
+    let mut scheme = EngineScheme::fill_scheme(data).unwrap();
+    let mut ratios = GearRatios::new(GearRule::new(b"*", 1), &scheme);
+    assert_eq!((scheme.part_sum(), ratios.sum()), (5, 5));
+
+    let error = ratios.set(&mut scheme, 0, 19, b'6').unwrap_err();
+    assert_eq!(
+        error,
+        NumberTooLarge {
+            row: 0,
+            start: 0,
+            end: 20
+        }
+    );
+    assert_eq!(scheme.to_string(), data);
+    assert_eq!((scheme.part_sum(), ratios.sum()), (5, 5));
+
+    ratios.set(&mut scheme, 0, 20, b'.').unwrap();
+    ratios.set(&mut scheme, 0, 19, b'5').unwrap();
+    assert_eq!(scheme.part_sum(), 0);
+    ratios.set(&mut scheme, 0, 20, b'*').unwrap();
+    assert_eq!(
+        (scheme.part_sum(), ratios.sum()),
+        (18446744073709551615, 18446744073709551615)
+    );
+}
@@ -0,0 +1,98 @@
+use day_3::{
+    gear::GearRule,
+    scheme::{EngineScheme, NumberTooLarge},
+};
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+fn values(scheme: &EngineScheme, rule: &GearRule) -> Vec<Vec<u64>> {
+    rule.gears(scheme)
+        .map(|gear| gear.numbers.iter().map(|number| number.value).collect())
+        .collect()
+}
+
+#[test]
+fn sample() {
+    let scheme = EngineScheme::fill_scheme(SAMPLE).unwrap();
+    assert_eq!(scheme.part_sum(), 4361);
+    assert_eq!(GearRule::default().ratio_sum(&scheme), 467835);
+}
+
+#[test]
+fn number_next_to_two_gears_counts_for_both() {
+    let scheme = EngineScheme::fill_scheme("2.3\n*5*\n").unwrap();
+    assert_eq!(
+        values(&scheme, &GearRule::default()),
+        vec![vec![2, 5], vec![3, 5]]
+    );
+    assert_eq!(GearRule::default().ratio_sum(&scheme), 25);
+}
+
+#[test]
+fn star_next_to_three_numbers_is_not_a_pair() {
+    let scheme = EngineScheme::fill_scheme("1.2\n.*.\n..3\n").unwrap();
+    assert_eq!(GearRule::default().ratio_sum(&scheme), 0);
+    assert_eq!(GearRule::new(b"*", 3).ratio_sum(&scheme), 6);
+}
+
+#[test]
+fn number_touching_a_star_twice_counts_once() {
+    let scheme = EngineScheme::fill_scheme("123\n.*.\n4..\n").unwrap();
+    assert_eq!(values(&scheme, &GearRule::default()), vec![vec![123, 4]]);
+}
+
+#[test]
+fn gears_on_first_and_last_rows() {
+    let scheme = EngineScheme::fill_scheme("*7.\n8..\n...\n..9\n.6*\n").unwrap();
+    assert_eq!(
+        values(&scheme, &GearRule::default()),
+        vec![vec![7, 8], vec![9, 6]]
+    );
+}
+
+#[test]
+fn gears_on_first_and_last_columns() {
+    let scheme = EngineScheme::fill_scheme("4...5\n*...*\n3...2\n").unwrap();
+    assert_eq!(GearRule::default().ratio_sum(&scheme), 4 * 3 + 5 * 2);
+}
+
+#[test]
+fn numbers_ending_on_the_last_column_are_parts() {
+    let scheme = EngineScheme::fill_scheme("..12\n.#..\n..34\n99..\n").unwrap();
+    assert_eq!(scheme.part_sum(), 12 + 34);
+}
+
+#[test]
+fn configurable_symbols() {
+    let scheme = EngineScheme::fill_scheme("2.3\n#.*\n4.5\n").unwrap();
+    assert_eq!(GearRule::default().ratio_sum(&scheme), 15);
+    assert_eq!(GearRule::new(b"#*", 2).ratio_sum(&scheme), 8 + 15);
+    assert_eq!(GearRule::new(b"#", 2).ratio_sum(&scheme), 8);
+}
+
+#[test]
+fn crlf_input() {
+    let scheme = EngineScheme::fill_scheme(&SAMPLE.replace('\n', "\r\n")).unwrap();
+    assert_eq!(GearRule::default().ratio_sum(&scheme), 467835);
+}
+
+#[test]
+fn numbers_too_large_for_u64() {
+    let scheme = EngineScheme::fill_scheme("18446744073709551615*\n").unwrap();
+    assert_eq!(scheme.part_sum(), u64::MAX);
+
+    let error = EngineScheme::fill_scheme("........................\n.1234567890123456789012*\n")
+        .unwrap_err();
+    assert_eq!(
+        error,
+        NumberTooLarge {
+            row: 1,
+            start: 1,
+            end: 22
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "line 2, columns 2-23: number does not fit in 64 bits"
+    );
+}
@@ -0,0 +1,53 @@
+use std::{env, time::Instant};
+
+use day_4::{
+    card::parse_cards,
+    cascade::cascade,
+    deck::{self, DeckSpec},
+    rules::{total_score, Doubling},
+};
+
+// big_deck [CARDS]
+fn main() {
+    let cards = env::args()
+        .nth(1)
+        .map(|cards| cards.parse().expect("CARDS should be a number"))
+        .unwrap_or(1_000_000);
+    let spec = DeckSpec {
+        cards,
+        ..DeckSpec::default()
+    };
+
+    let start = Instant::now();
+    let input = deck::generate(&spec);
+    println!(
+        "{:<10} {:>12?}  {} cards, {} MB",
+        "generate",
+        start.elapsed(),
+        cards,
+        input.len() / 1_000_000
+    );
+
+    let start = Instant::now();
+    let (_input, cards) = parse_cards(&input).expect("generated deck should parse");
+    println!("{:<10} {:>12?}", "parse", start.elapsed());
+
+    let start = Instant::now();
+    let score = total_score(&cards, &Doubling);
+    println!("{:<10} {:>12?}  score {score}", "score", start.elapsed());
+
+    let start = Instant::now();
+    let table = cascade(&cards).expect("copies should fit in a u128");
+    let total = table.total().expect("total should fit in a u128");
+    let most = table
+        .rows
+        .iter()
+        .map(|row| row.held)
+        .max()
+        .unwrap_or_default();
+    println!(
+        "{:<10} {:>12?}  {total} cards held, at most {most} of one card",
+        "cascade",
+        start.elapsed()
+    );
+}
@@ -1,68 +1,53 @@
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, line_ending, multispace0, multispace1},
-    multi::separated_list1,
-    sequence::preceded,
-    IResult,
+use std::{env, process};
+
+use day_4::{
+    card::parse_cards,
+    rules::{self, Doubling, Scoring},
 };
 
-#[derive(Debug)]
-struct LotteryCard {
-    id: u32,
-    winning_numbers: Vec<u32>,
-    found_numbers: Vec<u32>,
+struct Args {
+    scoring: Box<dyn Scoring>,
+    verbose: bool,
 }
 
-impl LotteryCard {
-    fn score(&self) -> u32 {
-        self.found_numbers.iter().fold(0, |mut acc, number| {
-            if self.winning_numbers.contains(number) {
-                if acc == 0 {
-                    acc = 1
-                } else {
-                    acc *= 2;
-                }
+// challenge1 [--scoring doubling|linear[:POINTS]|table:P0,P1,...|expr:EXPRESSION] [--verbose]
+// --verbose lists every card's score on stderr.
+fn parse_args() -> Result<Args, String> {
+    let mut parsed = Args {
+        scoring: Box::new(Doubling),
+        verbose: false,
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--scoring" => {
+                parsed.scoring =
+                    rules::parse_scoring(&args.next().ok_or("--scoring needs a value")?)?
             }
-            acc
-        })
+            "--verbose" => parsed.verbose = true,
+            other => return Err(format!("Unknown argument {other}")),
+        }
     }
-}
-
-fn card(input: &str) -> IResult<&str, LotteryCard> {
-    let (input, id) = preceded(tag("Card"), preceded(multispace0, complete::u32))(input)?;
-    let (input, winning_numbers) = preceded(
-        tag(":"),
-        preceded(multispace0, separated_list1(multispace0, complete::u32)),
-    )(input)?;
-    let (input, found_numbers) = preceded(
-        tag(" |"),
-        preceded(multispace0, separated_list1(multispace0, complete::u32)),
-    )(input)?;
-    Ok((
-        input,
-        LotteryCard {
-            id,
-            winning_numbers,
-            found_numbers,
-        },
-    ))
-}
-
-fn parse_cards(input: &str) -> IResult<&str, Vec<LotteryCard>> {
-    let (input, cards) = separated_list1(line_ending, card)(input)?;
-    Ok((input, cards))
+    Ok(parsed)
 }
 
 fn main() {
+    let args = parse_args().unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(2)
+    });
+
     let input = include_str!("./input1.txt");
     let (_input, cards) = parse_cards(input).expect("should parse");
-    let result: u32 = cards
+    let result: u128 = cards
         .iter()
         .map(|card| {
-            let score = card.score();
-            println!("Card {} - Score {}", card.id, score);
+            let score = args.scoring.score(card.matches());
+            if args.verbose {
+                eprintln!("Card {} - Score {}", card.id, score);
+            }
             score
         })
-        .sum();
+        .fold(0, u128::saturating_add);
     println!("{}", result);
 }
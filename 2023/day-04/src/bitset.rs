@@ -0,0 +1,42 @@
+/// Membership over `offset..offset + 64 * words.len()`, one bit per number.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NumberSet {
+    offset: u32,
+    words: Vec<u64>,
+}
+
+impl NumberSet {
+    /// Widest range a set will cover before `LotteryCard::matches` falls back
+    /// to a sorted search; 64 Ki numbers is 8 KiB of bits.
+    pub const MAX_SPAN: u32 = 1 << 16;
+
+    /// `None` if the numbers span more than `MAX_SPAN`.
+    pub fn new(numbers: &[u32]) -> Option<Self> {
+        let (Some(low), Some(high)) = (numbers.iter().min(), numbers.iter().max()) else {
+            return Some(Self::default());
+        };
+        let span = high - low;
+        if span >= Self::MAX_SPAN {
+            return None;
+        }
+        let mut set = NumberSet {
+            offset: *low,
+            words: vec![0; span as usize / 64 + 1],
+        };
+        for number in numbers {
+            let bit = (number - low) as usize;
+            set.words[bit / 64] |= 1 << (bit % 64);
+        }
+        Some(set)
+    }
+
+    pub fn contains(&self, number: u32) -> bool {
+        let Some(bit) = number.checked_sub(self.offset) else {
+            return false;
+        };
+        let bit = bit as usize;
+        self.words
+            .get(bit / 64)
+            .is_some_and(|word| word & (1 << (bit % 64)) != 0)
+    }
+}
@@ -0,0 +1,63 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending, multispace0},
+    multi::separated_list1,
+    sequence::preceded,
+    IResult,
+};
+
+use crate::bitset::NumberSet;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LotteryCard {
+    pub id: u32,
+    pub winning_numbers: Vec<u32>,
+    pub found_numbers: Vec<u32>,
+}
+
+impl LotteryCard {
+    /// How many found numbers are also winning numbers. Winning numbers go
+    /// into a bitset, or a sorted list when they are too spread out for one.
+    pub fn matches(&self) -> usize {
+        match NumberSet::new(&self.winning_numbers) {
+            Some(winning) => self
+                .found_numbers
+                .iter()
+                .filter(|number| winning.contains(**number))
+                .count(),
+            None => {
+                let mut winning = self.winning_numbers.clone();
+                winning.sort_unstable();
+                self.found_numbers
+                    .iter()
+                    .filter(|number| winning.binary_search(number).is_ok())
+                    .count()
+            }
+        }
+    }
+}
+
+fn card(input: &str) -> IResult<&str, LotteryCard> {
+    let (input, id) = preceded(tag("Card"), preceded(multispace0, complete::u32))(input)?;
+    let (input, winning_numbers) = preceded(
+        tag(":"),
+        preceded(multispace0, separated_list1(multispace0, complete::u32)),
+    )(input)?;
+    let (input, found_numbers) = preceded(
+        tag(" |"),
+        preceded(multispace0, separated_list1(multispace0, complete::u32)),
+    )(input)?;
+    Ok((
+        input,
+        LotteryCard {
+            id,
+            winning_numbers,
+            found_numbers,
+        },
+    ))
+}
+
+pub fn parse_cards(input: &str) -> IResult<&str, Vec<LotteryCard>> {
+    let (input, cards) = separated_list1(line_ending, card)(input)?;
+    Ok((input, cards))
+}
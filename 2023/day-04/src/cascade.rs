@@ -0,0 +1,106 @@
+use std::{error, fmt};
+
+use crate::{
+    card::LotteryCard,
+    rules::{CopyRule, NextMatches},
+};
+
+/// One line of the copy table. `held` is the original card plus `won` copies
+/// won from earlier cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardCopies {
+    pub id: u32,
+    pub matches: usize,
+    pub won: u128,
+    pub held: u128,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CopyTable {
+    pub rows: Vec<CardCopies>,
+}
+
+impl CopyTable {
+    /// Every card held at the end, originals included. `None` if that doesn't
+    /// fit in a `u128`.
+    pub fn total(&self) -> Option<u128> {
+        self.rows
+            .iter()
+            .try_fold(0u128, |total, row| total.checked_add(row.held))
+    }
+}
+
+/// A card would have held more than `u128::MAX` copies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyOverflow {
+    pub id: u32,
+}
+
+impl fmt::Display for CopyOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "card {} holds more than {} copies", self.id, u128::MAX)
+    }
+}
+
+impl error::Error for CopyOverflow {}
+
+impl fmt::Display for CopyTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>6} {:>7} {:>16} {:>16}",
+            "card", "matches", "won", "held"
+        )?;
+        for row in &self.rows {
+            writeln!(
+                f,
+                "{:>6} {:>7} {:>16} {:>16}",
+                row.id, row.matches, row.won, row.held
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The puzzle's cascade, where a card wins the next `matches` cards.
+pub fn cascade(cards: &[LotteryCard]) -> Result<CopyTable, CopyOverflow> {
+    cascade_with(cards, &NextMatches)
+}
+
+/// Each card hands its held copies to the cards `rule` targets. Rather than
+/// visiting each of those cards, a hand-out is recorded where it starts and
+/// one past where it stops, and a running total picks both up, so the whole
+/// cascade is a single forward pass over the deck. Wins past the last card
+/// are dropped.
+pub fn cascade_with(cards: &[LotteryCard], rule: &dyn CopyRule) -> Result<CopyTable, CopyOverflow> {
+    let mut starting = vec![0u128; cards.len() + 1];
+    let mut stopping = vec![0u128; cards.len() + 1];
+    let mut won = 0u128;
+    let rows = cards
+        .iter()
+        .enumerate()
+        .map(|(index, card)| {
+            let overflow = CopyOverflow { id: card.id };
+            // Every hand-out counted in `stopping[index]` was added to `won`
+            // earlier, so this can't underflow.
+            won = won.checked_add(starting[index]).ok_or(overflow)? - stopping[index];
+            let matches = card.matches();
+            let held = won.checked_add(1).ok_or(overflow)?;
+            for targets in rule.targets(matches) {
+                let start = (index + targets.start.max(1)).min(cards.len());
+                let end = (index + targets.end).min(cards.len());
+                if start < end {
+                    starting[start] = starting[start].checked_add(held).ok_or(overflow)?;
+                    stopping[end] = stopping[end].checked_add(held).ok_or(overflow)?;
+                }
+            }
+            Ok(CardCopies {
+                id: card.id,
+                matches,
+                won,
+                held,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(CopyTable { rows })
+}
@@ -0,0 +1,94 @@
+use std::fmt::Write;
+
+use aoc_utils::rng::Rng;
+
+/// Shape of a synthetic deck. Each card wins with a `win_percent` chance,
+/// and a winning card gets between 1 and `max_matches` matches. Keeping the
+/// expected number of cards a card reaches below one keeps copy counts
+/// bounded; push it past one and they grow exponentially down the deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeckSpec {
+    pub cards: usize,
+    pub winning: usize,
+    pub found: usize,
+    pub max_number: u32,
+    pub win_percent: u64,
+    pub max_matches: usize,
+    pub seed: u64,
+}
+
+impl Default for DeckSpec {
+    /// The puzzle's layout: 10 winning and 25 found numbers out of 1..=99.
+    fn default() -> Self {
+        DeckSpec {
+            cards: 1_000,
+            winning: 10,
+            found: 25,
+            max_number: 99,
+            win_percent: 25,
+            max_matches: 4,
+            seed: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+}
+
+// Partial Fisher-Yates over `pool`: the first `count` entries end up a
+// uniform sample without repeats.
+fn sample(pool: &mut [u32], count: usize, rng: &mut Rng) {
+    for index in 0..count.min(pool.len()) {
+        let pick = index + rng.below(pool.len() - index);
+        pool.swap(index, pick);
+    }
+}
+
+/// Writes the deck in the puzzle's `Card N: ... | ...` format, so generated
+/// decks go through the same parser as real input.
+///
+/// Panics if `max_number` is too small to draw the winning and found numbers
+/// without repeats, which a card with no matches needs.
+pub fn generate(spec: &DeckSpec) -> String {
+    let matches_cap = spec.max_matches.min(spec.winning).min(spec.found);
+    assert!(
+        spec.winning + spec.found <= spec.max_number as usize,
+        "1..={} is too small for {} winning and {} found numbers",
+        spec.max_number,
+        spec.winning,
+        spec.found
+    );
+    let id_width = spec.cards.to_string().len();
+    let number_width = spec.max_number.to_string().len();
+    // Seeded, so the same spec always gives the same deck.
+    let mut rng = Rng::new(spec.seed);
+    let mut pool: Vec<u32> = (1..=spec.max_number).collect();
+    let mut deck = String::with_capacity(spec.cards * (8 + (spec.winning + spec.found) * 3));
+
+    for id in 1..=spec.cards {
+        let matches = if rng.next_u64() % 100 < spec.win_percent && matches_cap > 0 {
+            1 + rng.below(matches_cap)
+        } else {
+            0
+        };
+        // The pool's head becomes the winning numbers, the next stretch the
+        // found numbers that miss; the hits are taken from the winners.
+        let misses = spec.found - matches;
+        sample(&mut pool, spec.winning + misses, &mut rng);
+        let (winning, rest) = pool.split_at(spec.winning);
+        let mut found: Vec<u32> = winning[..matches]
+            .iter()
+            .chain(&rest[..misses])
+            .copied()
+            .collect();
+        sample(&mut found, spec.found, &mut rng);
+
+        write!(deck, "Card {id:>id_width$}:").unwrap();
+        for number in winning {
+            write!(deck, " {number:>number_width$}").unwrap();
+        }
+        deck.push_str(" |");
+        for number in found {
+            write!(deck, " {number:>number_width$}").unwrap();
+        }
+        deck.push('\n');
+    }
+    deck
+}
@@ -0,0 +1,97 @@
+use nom::{
+    branch::alt,
+    character::complete::{char, i64, multispace0, one_of},
+    combinator::{all_consuming, map, value},
+    multi::many0,
+    sequence::{delimited, pair, preceded},
+    IResult,
+};
+
+/// Integer arithmetic over the match count `m`: `+ - * / %`, `^` for powers,
+/// parentheses and unary minus. `2^(m-1)` is the puzzle's doubling score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expression {
+    Constant(i64),
+    Matches,
+    Negate(Box<Expression>),
+    Binary(char, Box<Expression>, Box<Expression>),
+}
+
+impl Expression {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        all_consuming(delimited(multispace0, sum, multispace0))(text)
+            .map(|(_, expression)| expression)
+            .map_err(|error| format!("invalid expression {text:?}: {error}"))
+    }
+
+    /// `None` on division by zero or overflow. A negative power is zero, so
+    /// `2^(m-1)` scores nothing without matches.
+    pub fn evaluate(&self, matches: i64) -> Option<i64> {
+        match self {
+            Expression::Constant(value) => Some(*value),
+            Expression::Matches => Some(matches),
+            Expression::Negate(operand) => operand.evaluate(matches)?.checked_neg(),
+            Expression::Binary(operator, left, right) => {
+                let left = left.evaluate(matches)?;
+                let right = right.evaluate(matches)?;
+                match operator {
+                    '+' => left.checked_add(right),
+                    '-' => left.checked_sub(right),
+                    '*' => left.checked_mul(right),
+                    '/' => left.checked_div(right),
+                    '%' => left.checked_rem(right),
+                    '^' if right < 0 => Some(0),
+                    '^' => left.checked_pow(u32::try_from(right).ok()?),
+                    _ => unreachable!("the parser only builds known operators"),
+                }
+            }
+        }
+    }
+}
+
+fn token<'a, T>(
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, T> {
+    delimited(multispace0, parser, multispace0)
+}
+
+fn fold(first: Expression, rest: Vec<(char, Expression)>) -> Expression {
+    rest.into_iter().fold(first, |left, (operator, right)| {
+        Expression::Binary(operator, Box::new(left), Box::new(right))
+    })
+}
+
+fn atom(input: &str) -> IResult<&str, Expression> {
+    token(alt((
+        map(i64, Expression::Constant),
+        value(Expression::Matches, char('m')),
+        delimited(char('('), sum, char(')')),
+        map(preceded(char('-'), atom), |operand| {
+            Expression::Negate(Box::new(operand))
+        }),
+    )))(input)
+}
+
+// `^` binds tightest and groups to the right.
+fn power(input: &str) -> IResult<&str, Expression> {
+    let (input, base) = atom(input)?;
+    match preceded(token(char('^')), power)(input) {
+        Ok((input, exponent)) => Ok((
+            input,
+            Expression::Binary('^', Box::new(base), Box::new(exponent)),
+        )),
+        Err(_) => Ok((input, base)),
+    }
+}
+
+fn product(input: &str) -> IResult<&str, Expression> {
+    let (input, first) = power(input)?;
+    let (input, rest) = many0(pair(token(one_of("*/%")), power))(input)?;
+    Ok((input, fold(first, rest)))
+}
+
+fn sum(input: &str) -> IResult<&str, Expression> {
+    let (input, first) = product(input)?;
+    let (input, rest) = many0(pair(token(one_of("+-")), product))(input)?;
+    Ok((input, fold(first, rest)))
+}
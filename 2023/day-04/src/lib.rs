@@ -0,0 +1,6 @@
+pub mod bitset;
+pub mod card;
+pub mod cascade;
+pub mod deck;
+pub mod expression;
+pub mod rules;
@@ -0,0 +1,176 @@
+use std::ops::Range;
+
+use crate::{card::LotteryCard, expression::Expression};
+
+/// Points for a card with `matches` winning numbers. Scores saturate at
+/// `u128::MAX` rather than wrap.
+pub trait Scoring {
+    fn score(&self, matches: usize) -> u128;
+}
+
+/// Which later cards a card wins a copy of, as offsets from the card itself.
+/// Offsets must be at least one: a card can only win cards after it.
+pub trait CopyRule {
+    fn targets(&self, matches: usize) -> Vec<Range<usize>>;
+}
+
+/// The puzzle's part one score: 1, 2, 4, 8, ...
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Doubling;
+
+impl Scoring for Doubling {
+    fn score(&self, matches: usize) -> u128 {
+        match matches {
+            0 => 0,
+            matches if matches <= u128::BITS as usize => 1 << (matches - 1),
+            _ => u128::MAX,
+        }
+    }
+}
+
+/// `points` per match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Linear {
+    pub points: u64,
+}
+
+impl Scoring for Linear {
+    fn score(&self, matches: usize) -> u128 {
+        u128::from(self.points).saturating_mul(matches as u128)
+    }
+}
+
+/// `payouts[matches]`, with the last payout for any count past the end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayoutTable {
+    pub payouts: Vec<u64>,
+}
+
+impl Scoring for PayoutTable {
+    fn score(&self, matches: usize) -> u128 {
+        self.payouts
+            .get(matches)
+            .or(self.payouts.last())
+            .map_or(0, |payout| u128::from(*payout))
+    }
+}
+
+/// Negative results and anything the expression can't evaluate score zero.
+impl Scoring for Expression {
+    fn score(&self, matches: usize) -> u128 {
+        i64::try_from(matches)
+            .ok()
+            .and_then(|matches| self.evaluate(matches))
+            .and_then(|score| u128::try_from(score).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// The puzzle's part two rule: `matches` copies of the cards right after.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NextMatches;
+
+impl CopyRule for NextMatches {
+    fn targets(&self, matches: usize) -> Vec<Range<usize>> {
+        vec![Range {
+            start: 1,
+            end: matches + 1,
+        }]
+    }
+}
+
+/// Any win at all earns the next `count` cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NextN {
+    pub count: usize,
+}
+
+impl CopyRule for NextN {
+    fn targets(&self, matches: usize) -> Vec<Range<usize>> {
+        if matches == 0 {
+            vec![]
+        } else {
+            vec![Range {
+                start: 1,
+                end: self.count + 1,
+            }]
+        }
+    }
+}
+
+/// The n-th match earns the card `offsets[n]` places further on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Offsets {
+    pub offsets: Vec<usize>,
+}
+
+impl CopyRule for Offsets {
+    fn targets(&self, matches: usize) -> Vec<Range<usize>> {
+        self.offsets
+            .iter()
+            .take(matches)
+            .map(|offset| *offset..offset + 1)
+            .collect()
+    }
+}
+
+fn parse_list<T: std::str::FromStr>(text: &str) -> Result<Vec<T>, String> {
+    text.split(',')
+        .map(|item| {
+            item.trim()
+                .parse()
+                .map_err(|_| format!("expected a number, found {item:?}"))
+        })
+        .collect()
+}
+
+/// `doubling`, `linear[:POINTS]`, `table:P0,P1,...` or `expr:EXPRESSION`.
+pub fn parse_scoring(spec: &str) -> Result<Box<dyn Scoring>, String> {
+    let (name, argument) = spec.split_once(':').unwrap_or((spec, ""));
+    match (name, argument) {
+        ("doubling", "") => Ok(Box::new(Doubling)),
+        ("linear", "") => Ok(Box::new(Linear { points: 1 })),
+        ("linear", points) => Ok(Box::new(Linear {
+            points: points
+                .parse()
+                .map_err(|_| format!("expected points per match, found {points:?}"))?,
+        })),
+        ("table", payouts) => Ok(Box::new(PayoutTable {
+            payouts: parse_list(payouts)?,
+        })),
+        ("expr", expression) => Ok(Box::new(Expression::parse(expression)?)),
+        _ => Err(format!(
+            "unknown scoring {spec:?}, expected doubling, linear[:POINTS], table:P0,P1,... or expr:EXPRESSION"
+        )),
+    }
+}
+
+/// `matches`, `next:N` or `offsets:O1,O2,...`.
+pub fn parse_copy_rule(spec: &str) -> Result<Box<dyn CopyRule>, String> {
+    let (name, argument) = spec.split_once(':').unwrap_or((spec, ""));
+    match (name, argument) {
+        ("matches", "") => Ok(Box::new(NextMatches)),
+        ("next", count) => Ok(Box::new(NextN {
+            count: count
+                .parse()
+                .map_err(|_| format!("expected a card count, found {count:?}"))?,
+        })),
+        ("offsets", offsets) => {
+            let offsets: Vec<usize> = parse_list(offsets)?;
+            if offsets.contains(&0) {
+                return Err("offsets must be at least 1".to_owned());
+            }
+            Ok(Box::new(Offsets { offsets }))
+        }
+        _ => Err(format!(
+            "unknown copy rule {spec:?}, expected matches, next:N or offsets:O1,O2,..."
+        )),
+    }
+}
+
+pub fn total_score(cards: &[LotteryCard], scoring: &dyn Scoring) -> u128 {
+    cards
+        .iter()
+        .map(|card| scoring.score(card.matches()))
+        .fold(0, u128::saturating_add)
+}
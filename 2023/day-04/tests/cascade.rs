@@ -0,0 +1,66 @@
+use day_4::{
+    card::{parse_cards, LotteryCard},
+    cascade::{cascade, CardCopies},
+};
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+fn sample() -> Vec<LotteryCard> {
+    parse_cards(SAMPLE).unwrap().1
+}
+
+#[test]
+fn readme_copy_table() {
+    let table = cascade(&sample()).unwrap();
+    let rows: Vec<(u32, usize, u128, u128)> = table
+        .rows
+        .iter()
+        .map(|row| (row.id, row.matches, row.won, row.held))
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            (1, 4, 0, 1),
+            (2, 2, 1, 2),
+            (3, 2, 3, 4),
+            (4, 1, 7, 8),
+            (5, 0, 13, 14),
+            (6, 0, 0, 1),
+        ]
+    );
+    assert_eq!(table.total(), Some(30));
+}
+
+#[test]
+fn table_display_lists_every_card() {
+    let table = cascade(&sample()).unwrap();
+    let text = table.to_string();
+    assert_eq!(text.lines().count(), 7);
+    assert!(text.lines().next().unwrap().contains("held"));
+    assert_eq!(
+        text.lines()
+            .nth(5)
+            .unwrap()
+            .split_whitespace()
+            .collect::<Vec<_>>(),
+        vec!["5", "0", "13", "14"]
+    );
+}
+
+#[test]
+fn wins_past_the_last_card_are_dropped() {
+    let cards = parse_cards("Card 1: 1 2 | 1 2\nCard 2: 3 4 5 | 3 4 5\n")
+        .unwrap()
+        .1;
+    let table = cascade(&cards).unwrap();
+    assert_eq!(
+        table.rows[1],
+        CardCopies {
+            id: 2,
+            matches: 3,
+            won: 1,
+            held: 2,
+        }
+    );
+    assert_eq!(table.total(), Some(3));
+}
@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+use day_4::{
+    bitset::NumberSet,
+    card::{parse_cards, LotteryCard},
+    cascade::cascade,
+    deck::{generate, DeckSpec},
+    rules::{total_score, Doubling},
+};
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+// The same cards with every number spread far enough apart that the winning
+// numbers no longer fit a bitset.
+fn spread(cards: &[LotteryCard]) -> Vec<LotteryCard> {
+    let spread = |numbers: &[u32]| numbers.iter().map(|number| number * 1_000).collect();
+    cards
+        .iter()
+        .map(|card| LotteryCard {
+            id: card.id,
+            winning_numbers: spread(&card.winning_numbers),
+            found_numbers: spread(&card.found_numbers),
+        })
+        .collect()
+}
+
+#[test]
+fn number_set_membership() {
+    let set = NumberSet::new(&[41, 48, 83, 86, 17]).unwrap();
+    let found = [83, 86, 6, 31, 17, 9, 48, 53];
+    assert_eq!(
+        found.iter().filter(|number| set.contains(**number)).count(),
+        4
+    );
+    assert!(!set.contains(0));
+    assert!(!set.contains(u32::MAX));
+    assert!(!NumberSet::new(&[]).unwrap().contains(0));
+    assert!(NumberSet::new(&[5, 5 + NumberSet::MAX_SPAN - 1]).is_some());
+    assert!(NumberSet::new(&[5, 5 + NumberSet::MAX_SPAN]).is_none());
+}
+
+#[test]
+fn bitset_and_sorted_fallback_agree_on_the_readme() {
+    let cards = parse_cards(SAMPLE).unwrap().1;
+    let spread = spread(&cards);
+    assert!(NumberSet::new(&spread[0].winning_numbers).is_none());
+    for deck in [&cards, &spread] {
+        let matches: Vec<usize> = deck.iter().map(LotteryCard::matches).collect();
+        assert_eq!(matches, vec![4, 2, 2, 1, 0, 0]);
+        assert_eq!(total_score(deck, &Doubling), 13);
+        assert_eq!(cascade(deck).unwrap().total(), Some(30));
+    }
+}
+
+#[test]
+fn generated_cards_never_repeat_a_number() {
+    // Exactly enough numbers for a card without matches.
+    let spec = DeckSpec {
+        cards: 200,
+        winning: 10,
+        found: 25,
+        max_number: 35,
+        win_percent: 50,
+        max_matches: 5,
+        ..DeckSpec::default()
+    };
+    let cards = parse_cards(&generate(&spec)).unwrap().1;
+    assert_eq!(cards.len(), 200);
+    for card in &cards {
+        let winning: HashSet<u32> = card.winning_numbers.iter().copied().collect();
+        let found: HashSet<u32> = card.found_numbers.iter().copied().collect();
+        assert_eq!((winning.len(), found.len()), (10, 25));
+        assert!(card.matches() <= 5);
+    }
+    assert!(cards.iter().any(|card| card.matches() == 0));
+}
+
+#[test]
+#[should_panic(expected = "1..=30 is too small for 10 winning and 25 found numbers")]
+fn too_few_numbers_for_a_losing_card() {
+    generate(&DeckSpec {
+        winning: 10,
+        found: 25,
+        max_number: 30,
+        max_matches: 5,
+        ..DeckSpec::default()
+    });
+}
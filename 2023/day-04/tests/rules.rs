@@ -0,0 +1,94 @@
+use day_4::{
+    card::{parse_cards, LotteryCard},
+    cascade::cascade_with,
+    expression::Expression,
+    rules::{self, CopyRule, Doubling, Linear, NextN, Offsets, PayoutTable, Scoring},
+};
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+fn sample() -> Vec<LotteryCard> {
+    parse_cards(SAMPLE).unwrap().1
+}
+
+fn total(spec: &str) -> u128 {
+    rules::total_score(&sample(), rules::parse_scoring(spec).unwrap().as_ref())
+}
+
+fn copies(spec: &str) -> Option<u128> {
+    let rule = rules::parse_copy_rule(spec).unwrap();
+    cascade_with(&sample(), rule.as_ref()).unwrap().total()
+}
+
+#[test]
+fn readme_points_from_every_way_of_writing_doubling() {
+    assert_eq!(rules::total_score(&sample(), &Doubling), 13);
+    assert_eq!(total("doubling"), 13);
+    assert_eq!(total("table:0,1,2,4,8"), 13);
+    assert_eq!(total("expr:2^(m-1)"), 13);
+    assert_eq!(total("expr: 2 ^ (m - 1) "), 13);
+}
+
+#[test]
+fn other_scorings() {
+    // Matches are 4, 2, 2, 1, 0, 0.
+    assert_eq!(total("linear"), 9);
+    assert_eq!(total("linear:3"), 27);
+    assert_eq!(total("table:0,5"), 20);
+    assert_eq!(total("expr:m*m"), 16 + 4 + 4 + 1);
+}
+
+#[test]
+fn scores_saturate_or_fall_back() {
+    assert_eq!(Doubling.score(0), 0);
+    assert_eq!(Doubling.score(128), 1 << 127);
+    assert_eq!(Doubling.score(129), u128::MAX);
+    assert_eq!(Linear { points: 3 }.score(4), 12);
+    let table = PayoutTable {
+        payouts: vec![0, 1, 3],
+    };
+    assert_eq!(table.score(7), 3);
+    assert_eq!(PayoutTable { payouts: vec![] }.score(1), 0);
+    // Negative or undefined expressions score nothing.
+    assert_eq!(Expression::parse("m-10").unwrap().score(2), 0);
+    assert_eq!(Expression::parse("1/(m-2)").unwrap().score(2), 0);
+}
+
+#[test]
+fn readme_copies_from_equivalent_rules() {
+    assert_eq!(copies("matches"), Some(30));
+    // Offsets 1, 2, 3, ... reproduce the puzzle's rule.
+    assert_eq!(copies("offsets:1,2,3,4,5"), Some(30));
+}
+
+#[test]
+fn other_copy_rules() {
+    // Any win earns the next card: cards 1-4 win, each passing on all it holds.
+    assert_eq!(copies("next:1"), Some(1 + 2 + 3 + 4 + 5 + 1));
+    // The first match wins the card two places on.
+    assert_eq!(copies("offsets:2"), Some(1 + 1 + 2 + 2 + 3 + 3));
+    assert!(NextN { count: 3 }.targets(0).is_empty());
+    assert!(rules::parse_copy_rule("offsets:0,1").is_err());
+    assert!(rules::parse_copy_rule("sideways").is_err());
+    assert!(rules::parse_scoring("table:1,x").is_err());
+    let offsets = Offsets {
+        offsets: vec![3, 1],
+    };
+    assert_eq!(offsets.targets(5).len(), 2);
+}
+
+#[test]
+fn expression_precedence_and_errors() {
+    let evaluate = |text: &str, matches| Expression::parse(text).unwrap().evaluate(matches);
+    assert_eq!(evaluate("1 + 2 * 3", 0), Some(7));
+    assert_eq!(evaluate("(1 + 2) * 3", 0), Some(9));
+    assert_eq!(evaluate("2 ^ 3 ^ 2", 0), Some(512));
+    assert_eq!(evaluate("-m + 10 % 4", 3), Some(-1));
+    assert_eq!(evaluate("7 / 2 - -1", 0), Some(4));
+    assert_eq!(evaluate("2^(m-1)", 0), Some(0));
+    assert_eq!(evaluate("m / 0", 1), None);
+    assert_eq!(evaluate("2 ^ 63", 0), None);
+    for bad in ["", "1 +", "(m", "m m", "x"] {
+        assert!(Expression::parse(bad).is_err(), "{bad:?}");
+    }
+}
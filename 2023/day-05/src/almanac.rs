@@ -0,0 +1,409 @@
+use std::{
+    collections::{BTreeSet, HashMap, VecDeque},
+    error, fmt,
+    ops::Range,
+};
+
+use nom::{
+    bytes::complete::{tag, take_until},
+    character::complete::{line_ending, multispace0, multispace1, space1, u64},
+    combinator::cut,
+    multi::{many1, separated_list1},
+    sequence::{preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::interval;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlmanacError {
+    Syntax { line: usize },
+    UnknownCategory(String),
+    DuplicateMap { source: String, destination: String },
+    Disconnected { unreachable: Vec<String> },
+    NoPath { from: String, to: String },
+    OddSeedCount(usize),
+}
+
+impl fmt::Display for AlmanacError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlmanacError::Syntax { line } => write!(f, "line {line}: unexpected input"),
+            AlmanacError::UnknownCategory(category) => write!(f, "unknown category {category:?}"),
+            AlmanacError::DuplicateMap {
+                source,
+                destination,
+            } => write!(f, "{source}-to-{destination} map appears more than once"),
+            AlmanacError::Disconnected { unreachable } => write!(
+                f,
+                "no chain of maps leads from seed to {}",
+                unreachable.join(", ")
+            ),
+            AlmanacError::NoPath { from, to } => {
+                write!(f, "no chain of maps leads from {from} to {to}")
+            }
+            AlmanacError::OddSeedCount(count) => {
+                write!(f, "{count} seeds can't be read as start length pairs")
+            }
+        }
+    }
+}
+
+impl error::Error for AlmanacError {}
+
+/// One `X-to-Y map:` section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryMap {
+    pub source: String,
+    pub destination: String,
+    pub map: ToMap,
+}
+
+/// The seeds plus every map section, in input order. Categories and the maps
+/// between them form a directed graph rooted at `seed`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<CategoryMap>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedRange {
+    pub start_index: u64,
+    pub range_length: u64,
+}
+
+impl SeedRange {
+    pub fn range(&self) -> Range<u64> {
+        self.start_index..self.start_index + self.range_length
+    }
+}
+
+impl Almanac {
+    /// Parses and validates: every map is named once and every category can
+    /// be reached from `seed`.
+    pub fn parse(input: &str) -> Result<Self, AlmanacError> {
+        let (rest, almanac) = parse_almanac(input).map_err(|error| {
+            let rest = match error {
+                nom::Err::Error(error) | nom::Err::Failure(error) => error.input,
+                nom::Err::Incomplete(_) => "",
+            };
+            syntax_error(input, rest)
+        })?;
+        if !rest.trim().is_empty() {
+            return Err(syntax_error(input, rest.trim_start()));
+        }
+        almanac.validate()?;
+        Ok(almanac)
+    }
+
+    pub fn validate(&self) -> Result<(), AlmanacError> {
+        let mut names = BTreeSet::new();
+        for map in &self.maps {
+            if !names.insert((&map.source, &map.destination)) {
+                return Err(AlmanacError::DuplicateMap {
+                    source: map.source.clone(),
+                    destination: map.destination.clone(),
+                });
+            }
+        }
+        let reachable = self.reachable_from("seed");
+        let unreachable: Vec<String> = self
+            .categories()
+            .into_iter()
+            .filter(|category| !reachable.contains_key(category))
+            .map(str::to_owned)
+            .collect();
+        if unreachable.is_empty() {
+            Ok(())
+        } else {
+            Err(AlmanacError::Disconnected { unreachable })
+        }
+    }
+
+    /// `seed` followed by every category a map mentions, each once, in the
+    /// order they first appear.
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories = vec!["seed"];
+        for map in &self.maps {
+            for category in [map.source.as_str(), map.destination.as_str()] {
+                if !categories.contains(&category) {
+                    categories.push(category);
+                }
+            }
+        }
+        categories
+    }
+
+    // Breadth-first over the maps, so each reachable category records the
+    // map that first reached it along a shortest chain.
+    fn reachable_from<'a>(&'a self, from: &'a str) -> HashMap<&'a str, Option<usize>> {
+        let mut reached = HashMap::from([(from, None)]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(category) = queue.pop_front() {
+            for (index, map) in self.maps.iter().enumerate() {
+                if map.source == category && !reached.contains_key(map.destination.as_str()) {
+                    reached.insert(map.destination.as_str(), Some(index));
+                    queue.push_back(&map.destination);
+                }
+            }
+        }
+        reached
+    }
+
+    /// The shortest chain of maps converting `from` into `to`; empty when they
+    /// are the same category.
+    pub fn path(&self, from: &str, to: &str) -> Result<Vec<&CategoryMap>, AlmanacError> {
+        for category in [from, to] {
+            if !self.categories().contains(&category) {
+                return Err(AlmanacError::UnknownCategory(category.to_owned()));
+            }
+        }
+        let reached = self.reachable_from(from);
+        let mut path = vec![];
+        let mut category = to;
+        while category != from {
+            let Some(Some(index)) = reached.get(category) else {
+                return Err(AlmanacError::NoPath {
+                    from: from.to_owned(),
+                    to: to.to_owned(),
+                });
+            };
+            path.push(&self.maps[*index]);
+            category = &self.maps[*index].source;
+        }
+        path.reverse();
+        Ok(path)
+    }
+
+    pub fn convert(&self, from: &str, to: &str, value: u64) -> Result<u64, AlmanacError> {
+        Ok(self
+            .path(from, to)?
+            .iter()
+            .fold(value, |value, map| map.map.source_to_dest(value)))
+    }
+
+    /// Every `to` value reachable from the `from` ranges, as sorted disjoint
+    /// ranges.
+    pub fn convert_ranges(
+        &self,
+        from: &str,
+        to: &str,
+        ranges: &[Range<u64>],
+    ) -> Result<Vec<Range<u64>>, AlmanacError> {
+        Ok(self
+            .path(from, to)?
+            .iter()
+            .fold(interval::normalize(ranges.to_vec()), |ranges, map| {
+                map.map.map_ranges(&ranges)
+            }))
+    }
+
+    /// The chain from `from` to `to` collapsed into one normalized map.
+    pub fn compose(&self, from: &str, to: &str) -> Result<ToMap, AlmanacError> {
+        Ok(self
+            .path(from, to)?
+            .iter()
+            .fold(ToMap { ranges: vec![] }, |composed, map| {
+                composed.then(&map.map)
+            }))
+    }
+
+    /// Every `from` value that converts into one of the `to` ranges, e.g. the
+    /// seeds that end up in a location range.
+    pub fn preimage(
+        &self,
+        from: &str,
+        to: &str,
+        ranges: &[Range<u64>],
+    ) -> Result<Vec<Range<u64>>, AlmanacError> {
+        Ok(self
+            .path(from, to)?
+            .iter()
+            .rev()
+            .fold(interval::normalize(ranges.to_vec()), |ranges, map| {
+                map.map.preimage(&ranges)
+            }))
+    }
+
+    /// Part two reads the seed list as `start length` pairs, so an odd seed
+    /// count is an error rather than a dropped seed.
+    pub fn seed_ranges(&self) -> Result<Vec<SeedRange>, AlmanacError> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(AlmanacError::OddSeedCount(self.seeds.len()));
+        }
+        Ok(self
+            .seeds
+            .chunks_exact(2)
+            .map(|pair| SeedRange {
+                start_index: pair[0],
+                range_length: pair[1],
+            })
+            .collect())
+    }
+}
+
+fn syntax_error(source: &str, rest: &str) -> AlmanacError {
+    let offset = source.len() - rest.len();
+    AlmanacError::Syntax {
+        line: source[..offset].matches('\n').count() + 1,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToMap {
+    pub ranges: Vec<ToMapRange>,
+}
+
+impl ToMap {
+    /// The first range covering `input` moves it; anything else maps to itself.
+    pub fn source_to_dest(&self, input: u64) -> u64 {
+        self.ranges
+            .iter()
+            .find(|range| range.source().contains(&input))
+            .map_or(input, |range| {
+                range.destination_range_start + (input - range.source_range_start)
+            })
+    }
+
+    /// Index of the range that moves `input`, if any.
+    pub fn matching_range(&self, input: u64) -> Option<usize> {
+        self.ranges
+            .iter()
+            .position(|range| range.source().contains(&input))
+    }
+
+    /// Pushes whole ranges through the map, splitting them wherever a map
+    /// range starts or ends. Like `source_to_dest`, the first map range
+    /// covering a value wins and uncovered values map to themselves.
+    pub fn map_ranges(&self, inputs: &[Range<u64>]) -> Vec<Range<u64>> {
+        interval::normalize(
+            self.pieces(inputs)
+                .into_iter()
+                .map(|piece| piece.destination)
+                .collect(),
+        )
+    }
+
+    /// The split `map_ranges` makes, keeping which map range moved each
+    /// piece. Pieces come out sorted by source.
+    pub fn pieces(&self, inputs: &[Range<u64>]) -> Vec<Piece> {
+        let mut unmapped = inputs.to_vec();
+        let mut pieces = vec![];
+        for (index, range) in self.ranges.iter().enumerate() {
+            let source = range.source();
+            let mut rest = vec![];
+            for input in unmapped {
+                let overlap = input.start.max(source.start)..input.end.min(source.end);
+                if overlap.is_empty() {
+                    rest.push(input);
+                    continue;
+                }
+                pieces.push(Piece {
+                    destination: range.shift(overlap.start)..range.shift(overlap.end),
+                    source: overlap.clone(),
+                    matched: Some(index),
+                });
+                if input.start < overlap.start {
+                    rest.push(input.start..overlap.start);
+                }
+                if overlap.end < input.end {
+                    rest.push(overlap.end..input.end);
+                }
+            }
+            unmapped = rest;
+        }
+        pieces.extend(
+            unmapped
+                .into_iter()
+                .filter(|input| !input.is_empty())
+                .map(|input| Piece {
+                    source: input.clone(),
+                    destination: input,
+                    matched: None,
+                }),
+        );
+        pieces.sort_unstable_by_key(|piece| piece.source.start);
+        pieces
+    }
+}
+
+/// Part of an input range and where the map sends it. `matched` is the
+/// index of the map range that moved it, or `None` if nothing covered it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piece {
+    pub source: Range<u64>,
+    pub destination: Range<u64>,
+    pub matched: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToMapRange {
+    pub destination_range_start: u64,
+    pub source_range_start: u64,
+    pub range_length: u64,
+}
+
+impl ToMapRange {
+    pub fn source(&self) -> Range<u64> {
+        self.source_range_start..self.source_range_start + self.range_length
+    }
+
+    pub fn destination(&self) -> Range<u64> {
+        self.destination_range_start..self.destination_range_start + self.range_length
+    }
+
+    // Where `value` lands, for `value` in `source()` or at its end.
+    fn shift(&self, value: u64) -> u64 {
+        self.destination_range_start + (value - self.source_range_start)
+    }
+}
+
+fn parse_to_map_range(input: &str) -> IResult<&str, ToMapRange> {
+    let (input, (dest_range_start, _, source_range_start, _, range_length)) =
+        tuple((u64, space1, u64, space1, u64))(input)?;
+    Ok((
+        input,
+        ToMapRange {
+            destination_range_start: dest_range_start,
+            source_range_start,
+            range_length,
+        },
+    ))
+}
+
+fn parse_to_map(input: &str) -> IResult<&str, ToMap> {
+    let (input, ranges) = separated_list1(line_ending, parse_to_map_range)(input)?;
+    Ok((input, ToMap { ranges }))
+}
+
+fn parse_category_map(input: &str) -> IResult<&str, CategoryMap> {
+    let (rest, name) = terminated(take_until(" map:"), tag(" map:"))(input)?;
+    let Some((source, destination)) = name.split_once("-to-").filter(|(source, destination)| {
+        !source.is_empty() && !destination.is_empty() && !name.contains('\n')
+    }) else {
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Tag,
+        )));
+    };
+    let (rest, map) = cut(preceded(multispace1, parse_to_map))(rest)?;
+    Ok((
+        rest,
+        CategoryMap {
+            source: source.trim().to_owned(),
+            destination: destination.trim().to_owned(),
+            map,
+        },
+    ))
+}
+
+/// Parses without validating; see `Almanac::parse`.
+pub fn parse_almanac(input: &str) -> IResult<&str, Almanac> {
+    let (input, seeds) = preceded(
+        tag("seeds:"),
+        preceded(multispace1, separated_list1(multispace1, u64)),
+    )(input)?;
+    let (input, maps) = many1(preceded(multispace0, parse_category_map))(input)?;
+    Ok((input, Almanac { seeds, maps }))
+}
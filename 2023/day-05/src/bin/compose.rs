@@ -0,0 +1,78 @@
+use std::{env, ops::Range, process};
+
+use day_5::{almanac::Almanac, options::Options};
+
+struct Args {
+    options: Options,
+    invert: bool,
+    preimages: Vec<Range<u64>>,
+}
+
+fn parse_range(text: &str) -> Option<Range<u64>> {
+    let (start, end) = text.split_once("..")?;
+    Some(start.trim().parse().ok()?..end.trim().parse().ok()?)
+}
+
+// compose [--from CATEGORY] [--to CATEGORY] [--invert] [--preimage START..END]...
+// Prints the collapsed map's breakpoints, or its inverse, then the `from`
+// ranges that convert into each requested `to` range.
+fn parse_args() -> Result<Args, String> {
+    let (options, rest) = Options::parse_known(env::args().skip(1))?;
+    let mut parsed = Args {
+        options,
+        invert: false,
+        preimages: vec![],
+    };
+    let mut args = rest.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--invert" => parsed.invert = true,
+            "--preimage" => {
+                let text = value()?;
+                parsed
+                    .preimages
+                    .push(parse_range(&text).ok_or(format!("Expected START..END, found {text}"))?)
+            }
+            other => return Err(format!("Unknown argument {other}")),
+        }
+    }
+    Ok(parsed)
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(2)
+    });
+
+    let input = include_str!("input1.txt");
+    let almanac = Almanac::parse(input).unwrap_or_else(|error| panic!("Should parse: {error}"));
+    let fail = |error: &dyn std::fmt::Display| -> ! {
+        eprintln!("{error}");
+        process::exit(1)
+    };
+
+    let Options { from, to } = &args.options;
+    let composed = almanac
+        .compose(from, to)
+        .unwrap_or_else(|error| fail(&error));
+    if args.invert {
+        let inverse = composed
+            .inverse()
+            .unwrap_or_else(|| fail(&format!("{from}-to-{to} is not invertible")));
+        println!("{to}-to-{from} map:");
+        print!("{inverse}");
+    } else {
+        println!("{from}-to-{to} map:");
+        print!("{composed}");
+    }
+
+    for target in &args.preimages {
+        let sources = almanac
+            .preimage(from, to, std::slice::from_ref(target))
+            .unwrap_or_else(|error| fail(&error));
+        println!();
+        println!("{to} {target:?} comes from {from} {sources:?}");
+    }
+}
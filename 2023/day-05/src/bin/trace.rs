@@ -0,0 +1,104 @@
+use std::{env, fs, ops::Range, process};
+
+use day_5::{almanac::Almanac, options::Options};
+
+struct Args {
+    input: Option<String>,
+    options: Options,
+    ranges: bool,
+    values: Vec<u64>,
+    value_ranges: Vec<Range<u64>>,
+}
+
+// trace [--input PATH] [--from CATEGORY] [--to CATEGORY] [--ranges] [VALUE|START..END]...
+// Without values, traces the almanac's seeds, or its seed ranges with --ranges.
+// Overlapping map ranges are reported before anything is traced.
+fn parse_args() -> Result<Args, String> {
+    let (options, rest) = Options::parse_known(env::args().skip(1))?;
+    let mut parsed = Args {
+        input: None,
+        options,
+        ranges: false,
+        values: vec![],
+        value_ranges: vec![],
+    };
+    let mut args = rest.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--input" => parsed.input = Some(value()?),
+            "--ranges" => parsed.ranges = true,
+            text => match text.split_once("..") {
+                Some((start, end)) => {
+                    let range = start.parse().ok().zip(end.parse().ok());
+                    let (start, end) = range.ok_or(format!("Expected START..END, found {text}"))?;
+                    parsed.value_ranges.push(start..end);
+                }
+                None => parsed.values.push(
+                    text.parse()
+                        .map_err(|_| format!("Expected a value, found {text}"))?,
+                ),
+            },
+        }
+    }
+    Ok(parsed)
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(2)
+    });
+
+    let input = match &args.input {
+        Some(path) => fs::read_to_string(path).unwrap_or_else(|error| panic!("{path}: {error}")),
+        None => include_str!("input1.txt").to_owned(),
+    };
+    let almanac = Almanac::parse(&input).unwrap_or_else(|error| panic!("Should parse: {error}"));
+    let fail = |error: &dyn std::fmt::Display| -> ! {
+        eprintln!("{error}");
+        process::exit(1)
+    };
+
+    let overlaps = almanac.overlaps();
+    for overlap in &overlaps {
+        eprintln!("warning: {overlap}");
+    }
+    if !overlaps.is_empty() {
+        eprintln!();
+    }
+
+    let Options { from, to } = &args.options;
+    let (mut values, mut value_ranges) = (args.values, args.value_ranges);
+    if values.is_empty() && value_ranges.is_empty() {
+        if args.ranges {
+            value_ranges = almanac
+                .seed_ranges()
+                .unwrap_or_else(|error| fail(&error))
+                .iter()
+                .map(|seeds| seeds.range())
+                .collect();
+        } else {
+            values = almanac.seeds.clone();
+        }
+    }
+
+    for value in values {
+        println!("{from} {value}");
+        for step in almanac
+            .trace(from, to, value)
+            .unwrap_or_else(|error| fail(&error))
+        {
+            println!("  {step}");
+        }
+    }
+    for range in value_ranges {
+        println!("{from} {range:?}");
+        for step in almanac
+            .trace_ranges(from, to, std::slice::from_ref(&range))
+            .unwrap_or_else(|error| fail(&error))
+        {
+            println!("  {}", step.to_string().replace('\n', "\n  "));
+        }
+    }
+}
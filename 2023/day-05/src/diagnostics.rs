@@ -0,0 +1,160 @@
+use std::{fmt, ops::Range};
+
+use crate::almanac::{Almanac, AlmanacError, CategoryMap, Piece, ToMapRange};
+
+/// One map applied to a single value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step<'a> {
+    pub map: &'a CategoryMap,
+    pub input: u64,
+    pub output: u64,
+    pub matched: Option<usize>,
+}
+
+/// One map applied to a set of ranges, split the way the map splits them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeStep<'a> {
+    pub map: &'a CategoryMap,
+    pub pieces: Vec<Piece>,
+}
+
+/// Two ranges of one map that both claim some inputs. `source_to_dest` uses
+/// `first`, so `second` never applies to `overlap`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overlap<'a> {
+    pub map: &'a CategoryMap,
+    pub first: usize,
+    pub second: usize,
+    pub overlap: Range<u64>,
+}
+
+fn describe(map: &CategoryMap, matched: Option<usize>) -> String {
+    match matched {
+        Some(index) => {
+            let ToMapRange {
+                destination_range_start,
+                source_range_start,
+                range_length,
+            } = map.map.ranges[index];
+            format!(
+                "range #{} ({destination_range_start} {source_range_start} {range_length})",
+                index + 1
+            )
+        }
+        None => "unmapped".to_owned(),
+    }
+}
+
+impl fmt::Display for Step<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<28} {:>12} -> {:<12} {}",
+            format!("{}-to-{}", self.map.source, self.map.destination),
+            self.input,
+            self.output,
+            describe(self.map, self.matched)
+        )
+    }
+}
+
+impl fmt::Display for RangeStep<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-to-{}", self.map.source, self.map.destination)?;
+        for piece in &self.pieces {
+            write!(
+                f,
+                "\n  {:>25} -> {:<25} {}",
+                format!("{:?}", piece.source),
+                format!("{:?}", piece.destination),
+                describe(self.map, piece.matched)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Overlap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-to-{} map: {} and {} both cover {:?}",
+            self.map.source,
+            self.map.destination,
+            describe(self.map, Some(self.first)),
+            describe(self.map, Some(self.second)),
+            self.overlap
+        )
+    }
+}
+
+impl Almanac {
+    /// Each map on the way from `from` to `to` with the value going in and out.
+    pub fn trace(&self, from: &str, to: &str, value: u64) -> Result<Vec<Step<'_>>, AlmanacError> {
+        let mut input = value;
+        Ok(self
+            .path(from, to)?
+            .into_iter()
+            .map(|map| {
+                let matched = map.map.matching_range(input);
+                let output = map.map.source_to_dest(input);
+                let step = Step {
+                    map,
+                    input,
+                    output,
+                    matched,
+                };
+                input = output;
+                step
+            })
+            .collect())
+    }
+
+    /// Like `trace`, for ranges. Each step lists the pieces the incoming
+    /// ranges were cut into.
+    pub fn trace_ranges(
+        &self,
+        from: &str,
+        to: &str,
+        ranges: &[Range<u64>],
+    ) -> Result<Vec<RangeStep<'_>>, AlmanacError> {
+        let mut inputs = ranges.to_vec();
+        Ok(self
+            .path(from, to)?
+            .into_iter()
+            .map(|map| {
+                let pieces = map.map.pieces(&inputs);
+                inputs = pieces
+                    .iter()
+                    .map(|piece| piece.destination.clone())
+                    .collect();
+                RangeStep { map, pieces }
+            })
+            .collect())
+    }
+
+    /// Every pair of ranges within one map whose sources overlap. The puzzle
+    /// never has any, so each one is likely an input or editing mistake.
+    pub fn overlaps(&self) -> Vec<Overlap<'_>> {
+        let mut overlaps = vec![];
+        for map in &self.maps {
+            for (first, first_range) in map.map.ranges.iter().enumerate() {
+                for (second, second_range) in map.map.ranges.iter().enumerate().skip(first + 1) {
+                    let (first_source, second_source) =
+                        (first_range.source(), second_range.source());
+                    let overlap = first_source.start.max(second_source.start)
+                        ..first_source.end.min(second_source.end);
+                    if !overlap.is_empty() {
+                        overlaps.push(Overlap {
+                            map,
+                            first,
+                            second,
+                            overlap,
+                        });
+                    }
+                }
+            }
+        }
+        overlaps
+    }
+}
@@ -0,0 +1,5 @@
+pub mod almanac;
+pub mod diagnostics;
+pub mod interval;
+pub mod options;
+pub mod piecewise;
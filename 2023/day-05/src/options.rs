@@ -0,0 +1,45 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub from: String,
+    pub to: String,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            from: "seed".to_owned(),
+            to: "location".to_owned(),
+        }
+    }
+}
+
+impl Options {
+    /// `[--from CATEGORY] [--to CATEGORY]`. The seed list is read as `from`
+    /// values; without flags that is seed to location as in the puzzle.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let (options, rest) = Options::parse_known(args)?;
+        match rest.first() {
+            Some(other) => Err(format!("Unknown argument {other}")),
+            None => Ok(options),
+        }
+    }
+
+    /// Like [`Options::parse`], but hands back every other argument in order
+    /// for binaries with flags of their own.
+    pub fn parse_known(
+        args: impl IntoIterator<Item = String>,
+    ) -> Result<(Self, Vec<String>), String> {
+        let mut options = Options::default();
+        let mut rest = vec![];
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--from" => options.from = value()?,
+                "--to" => options.to = value()?,
+                _ => rest.push(arg),
+            }
+        }
+        Ok((options, rest))
+    }
+}
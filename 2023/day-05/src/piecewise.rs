@@ -0,0 +1,178 @@
+use std::{fmt, ops::Range};
+
+use crate::{
+    almanac::{ToMap, ToMapRange},
+    interval,
+};
+
+/// A stretch of inputs that all move by the same `offset`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub source: Range<u64>,
+    pub offset: i128,
+}
+
+impl Segment {
+    pub fn image(&self) -> Range<u64> {
+        self.shift(self.source.start)..self.shift(self.source.end)
+    }
+
+    fn shift(&self, value: u64) -> u64 {
+        (i128::from(value) + self.offset) as u64
+    }
+
+    fn unshift(&self, value: u64) -> u64 {
+        (i128::from(value) - self.offset) as u64
+    }
+}
+
+// Sorts by source and merges neighbours that move by the same offset.
+fn merge(mut segments: Vec<Segment>) -> Vec<Segment> {
+    segments.retain(|segment| !segment.source.is_empty());
+    segments.sort_unstable_by_key(|segment| segment.source.start);
+    let mut merged: Vec<Segment> = Vec::with_capacity(segments.len());
+    for segment in segments {
+        match merged.last_mut() {
+            Some(last)
+                if last.source.end == segment.source.start && last.offset == segment.offset =>
+            {
+                last.source.end = segment.source.end
+            }
+            _ => merged.push(segment),
+        }
+    }
+    merged
+}
+
+fn from_segments(segments: Vec<Segment>) -> ToMap {
+    ToMap {
+        ranges: merge(segments)
+            .into_iter()
+            .filter(|segment| segment.offset != 0)
+            .map(|segment| ToMapRange {
+                destination_range_start: segment.shift(segment.source.start),
+                source_range_start: segment.source.start,
+                range_length: segment.source.end - segment.source.start,
+            })
+            .collect(),
+    }
+}
+
+impl ToMap {
+    /// The map as sorted segments tiling `0..u64::MAX`, identity stretches
+    /// included. Where map ranges overlap the first one wins, as in
+    /// `source_to_dest`.
+    pub fn segments(&self) -> Vec<Segment> {
+        let mut breakpoints: Vec<u64> = self
+            .ranges
+            .iter()
+            .flat_map(|range| [range.source().start, range.source().end])
+            .chain([0, u64::MAX])
+            .collect();
+        breakpoints.sort_unstable();
+        breakpoints.dedup();
+        let segments = breakpoints
+            .windows(2)
+            .map(|pair| {
+                let offset = self
+                    .ranges
+                    .iter()
+                    .find(|range| range.source().contains(&pair[0]))
+                    .map_or(0, |range| {
+                        i128::from(range.destination_range_start)
+                            - i128::from(range.source_range_start)
+                    });
+                Segment {
+                    source: pair[0]..pair[1],
+                    offset,
+                }
+            })
+            .collect();
+        merge(segments)
+    }
+
+    /// The same mapping with sorted, disjoint ranges, identity ranges dropped
+    /// and neighbours with the same offset joined.
+    pub fn normalized(&self) -> ToMap {
+        from_segments(self.segments())
+    }
+
+    /// `next` applied after `self`, as one normalized map.
+    pub fn then(&self, next: &ToMap) -> ToMap {
+        let next_segments = next.segments();
+        let mut composed = vec![];
+        for segment in self.segments() {
+            let image = segment.image();
+            for next_segment in &next_segments {
+                let overlap = image.start.max(next_segment.source.start)
+                    ..image.end.min(next_segment.source.end);
+                if overlap.is_empty() {
+                    continue;
+                }
+                composed.push(Segment {
+                    source: segment.unshift(overlap.start)..segment.unshift(overlap.end),
+                    offset: segment.offset + next_segment.offset,
+                });
+            }
+        }
+        from_segments(composed)
+    }
+
+    /// Every input that lands in one of `targets`, as sorted disjoint ranges.
+    pub fn preimage(&self, targets: &[Range<u64>]) -> Vec<Range<u64>> {
+        let targets = interval::normalize(targets.to_vec());
+        let mut sources = vec![];
+        for segment in self.segments() {
+            let image = segment.image();
+            for target in &targets {
+                let overlap = image.start.max(target.start)..image.end.min(target.end);
+                if !overlap.is_empty() {
+                    sources.push(segment.unshift(overlap.start)..segment.unshift(overlap.end));
+                }
+            }
+        }
+        interval::normalize(sources)
+    }
+
+    /// `None` unless every output comes from exactly one input, which is the
+    /// case when no range moves values onto another range or onto values the
+    /// map leaves alone.
+    pub fn inverse(&self) -> Option<ToMap> {
+        let mut images: Vec<Segment> = self
+            .segments()
+            .into_iter()
+            .map(|segment| Segment {
+                source: segment.image(),
+                offset: -segment.offset,
+            })
+            .collect();
+        images.sort_unstable_by_key(|segment| segment.source.start);
+        let tiles = images.first().is_some_and(|first| first.source.start == 0)
+            && images
+                .last()
+                .is_some_and(|last| last.source.end == u64::MAX)
+            && images
+                .windows(2)
+                .all(|pair| pair[0].source.end == pair[1].source.start);
+        tiles.then(|| from_segments(images))
+    }
+}
+
+/// One line per moved stretch, as `source -> destination (offset)`.
+impl fmt::Display for ToMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for range in &self.ranges {
+            let offset =
+                i128::from(range.destination_range_start) - i128::from(range.source_range_start);
+            writeln!(
+                f,
+                "{:>12}..{:<12} -> {:>12}..{:<12} ({offset:+})",
+                range.source().start,
+                range.source().end,
+                range.destination().start,
+                range.destination().end
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use std::ops::Range;
+
+use day_5::{
+    almanac::{Almanac, AlmanacError, SeedRange},
+    options::Options,
+};
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+#[test]
+fn lowest_location_for_a_seed() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    let locations: Vec<u64> = almanac
+        .seeds
+        .iter()
+        .map(|seed| almanac.convert("seed", "location", *seed).unwrap())
+        .collect();
+    assert_eq!(locations, [82, 43, 86, 35]);
+}
+
+#[test]
+fn lowest_location_for_a_seed_range() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    let seeds: Vec<Range<u64>> = almanac
+        .seed_ranges()
+        .unwrap()
+        .iter()
+        .map(SeedRange::range)
+        .collect();
+    assert_eq!(seeds, [79..93, 55..68]);
+    let locations = almanac.convert_ranges("seed", "location", &seeds).unwrap();
+    assert_eq!(locations.first().map(|range| range.start), Some(46));
+}
+
+#[test]
+fn odd_seed_count_is_an_error() {
+    let almanac = Almanac::parse(&SAMPLE.replacen("55 13", "55 13 7", 1)).unwrap();
+    assert_eq!(almanac.seed_ranges(), Err(AlmanacError::OddSeedCount(5)));
+    assert_eq!(
+        AlmanacError::OddSeedCount(5).to_string(),
+        "5 seeds can't be read as start length pairs"
+    );
+}
+
+#[test]
+fn options_default_to_seed_and_location() {
+    assert_eq!(
+        Options::parse(vec![]),
+        Ok(Options {
+            from: "seed".to_owned(),
+            to: "location".to_owned()
+        })
+    );
+    let options = Options::parse(["--to", "soil"].map(str::to_owned)).unwrap();
+    assert_eq!(
+        (options.from.as_str(), options.to.as_str()),
+        ("seed", "soil")
+    );
+    assert_eq!(
+        Options::parse(["--from".to_owned()]),
+        Err("--from needs a value".to_owned())
+    );
+    assert_eq!(
+        Options::parse(["--seeds".to_owned()]),
+        Err("Unknown argument --seeds".to_owned())
+    );
+}
+
+#[test]
+fn options_pass_other_arguments_through() {
+    let args = [
+        "--invert",
+        "--from",
+        "soil",
+        "--preimage",
+        "1..5",
+        "--to",
+        "water",
+    ];
+    let (options, rest) = Options::parse_known(args.map(str::to_owned)).unwrap();
+    assert_eq!(
+        (options.from.as_str(), options.to.as_str()),
+        ("soil", "water")
+    );
+    assert_eq!(rest, ["--invert", "--preimage", "1..5"]);
+    assert_eq!(
+        Options::parse_known(["--ranges", "--to"].map(str::to_owned)),
+        Err("--to needs a value".to_owned())
+    );
+}
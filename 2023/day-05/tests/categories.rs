@@ -0,0 +1,102 @@
+use day_5::almanac::{parse_almanac, Almanac, AlmanacError};
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+#[test]
+fn categories_follow_the_maps() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    assert_eq!(
+        almanac.categories(),
+        [
+            "seed",
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "humidity",
+            "location"
+        ]
+    );
+    let path: Vec<&str> = almanac
+        .path("soil", "light")
+        .unwrap()
+        .iter()
+        .map(|map| map.destination.as_str())
+        .collect();
+    assert_eq!(path, ["fertilizer", "water", "light"]);
+    assert!(almanac.path("water", "water").unwrap().is_empty());
+}
+
+#[test]
+fn maps_may_come_in_any_order() {
+    let (header, maps) = SAMPLE.split_once("\n\n").unwrap();
+    let mut sections: Vec<&str> = maps.trim_end().split("\n\n").collect();
+    sections.reverse();
+    let almanac = Almanac::parse(&format!("{header}\n\n{}\n", sections.join("\n\n"))).unwrap();
+    assert_eq!(almanac.maps[0].source, "humidity");
+    assert_eq!(almanac.convert("seed", "location", 13), Ok(35));
+}
+
+#[test]
+fn syntax_errors_give_the_line() {
+    assert_eq!(
+        Almanac::parse(&SAMPLE.replacen("52 50 48", "52 50 forty-eight", 1)),
+        Err(AlmanacError::Syntax { line: 5 })
+    );
+    assert_eq!(
+        Almanac::parse(&SAMPLE.replacen("water-to-light", "water to light", 1)),
+        Err(AlmanacError::Syntax { line: 18 })
+    );
+    assert_eq!(
+        AlmanacError::Syntax { line: 5 }.to_string(),
+        "line 5: unexpected input"
+    );
+}
+
+#[test]
+fn each_map_is_named_once() {
+    let input = format!("{SAMPLE}\nseed-to-soil map:\n0 1 2\n");
+    assert_eq!(
+        Almanac::parse(&input),
+        Err(AlmanacError::DuplicateMap {
+            source: "seed".to_owned(),
+            destination: "soil".to_owned()
+        })
+    );
+}
+
+#[test]
+fn every_category_is_reachable_from_seed() {
+    let input = format!("{SAMPLE}\ndust-to-soil map:\n0 1 2\n");
+    let error = Almanac::parse(&input).unwrap_err();
+    assert_eq!(
+        error,
+        AlmanacError::Disconnected {
+            unreachable: vec!["dust".to_owned()]
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "no chain of maps leads from seed to dust"
+    );
+    // Without validation the stray map is still parsed.
+    let (_, almanac) = parse_almanac(&input).unwrap();
+    assert_eq!(almanac.maps.len(), 8);
+}
+
+#[test]
+fn conversions_need_known_connected_categories() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    assert_eq!(
+        almanac.convert("seed", "gold", 79),
+        Err(AlmanacError::UnknownCategory("gold".to_owned()))
+    );
+    assert_eq!(
+        almanac.convert("location", "seed", 35),
+        Err(AlmanacError::NoPath {
+            from: "location".to_owned(),
+            to: "seed".to_owned()
+        })
+    );
+}
@@ -0,0 +1,61 @@
+#![allow(clippy::single_range_in_vec_init)]
+
+use day_5::almanac::Almanac;
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+#[test]
+fn trace_follows_seed_79_to_location_82() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    let steps = almanac.trace("seed", "location", 79).unwrap();
+    let values: Vec<u64> = steps.iter().map(|step| step.output).collect();
+    assert_eq!(values, [81, 81, 81, 74, 78, 78, 82]);
+    assert_eq!(steps[0].matched, Some(1));
+    assert_eq!(steps[1].matched, None);
+    let line = steps[0].to_string();
+    assert!(line.starts_with("seed-to-soil"), "{line}");
+    assert!(line.ends_with("range #2 (52 50 48)"), "{line}");
+    assert!(steps[1].to_string().ends_with("unmapped"));
+}
+
+#[test]
+fn trace_ranges_ends_where_convert_ranges_does() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    let seeds = [79..93, 55..68];
+    let steps = almanac.trace_ranges("seed", "location", &seeds).unwrap();
+    assert_eq!(steps.len(), 7);
+    let lowest = steps[6]
+        .pieces
+        .iter()
+        .map(|piece| piece.destination.start)
+        .min();
+    assert_eq!(lowest, Some(46));
+    let covered: u64 = steps[6]
+        .pieces
+        .iter()
+        .map(|piece| piece.destination.end - piece.destination.start)
+        .sum();
+    assert_eq!(covered, 14 + 13);
+}
+
+#[test]
+fn sample_has_no_overlaps() {
+    assert!(Almanac::parse(SAMPLE).unwrap().overlaps().is_empty());
+}
+
+#[test]
+fn overlapping_ranges_are_reported_in_map_order() {
+    let almanac = Almanac::parse(&SAMPLE.replacen("52 50 48", "52 50 48\n10 90 20", 1)).unwrap();
+    let overlaps = almanac.overlaps();
+    let found: Vec<_> = overlaps
+        .iter()
+        .map(|overlap| (overlap.first, overlap.second, overlap.overlap.clone()))
+        .collect();
+    assert_eq!(found, [(0, 2, 98..100), (1, 2, 90..98)]);
+    assert_eq!(
+        overlaps[0].to_string(),
+        "seed-to-soil map: range #1 (50 98 2) and range #3 (10 90 20) both cover 98..100"
+    );
+    // The first range keeps winning, as in the puzzle.
+    assert_eq!(almanac.convert("seed", "soil", 99), Ok(51));
+}
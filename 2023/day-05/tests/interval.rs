@@ -0,0 +1,97 @@
+#![allow(clippy::single_range_in_vec_init)]
+
+use day_5::{
+    almanac::{Almanac, Piece, ToMap, ToMapRange},
+    interval,
+};
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+fn seed_to_soil() -> ToMap {
+    Almanac::parse(SAMPLE).unwrap().maps[0].map.clone()
+}
+
+#[test]
+fn normalize_merges_overlapping_and_touching_ranges() {
+    assert_eq!(
+        interval::normalize(vec![10..12, 3..5, 5..7, 4..6, 20..20, 11..15]),
+        [3..7, 10..15]
+    );
+    assert!(interval::normalize(vec![4..4]).is_empty());
+}
+
+#[test]
+fn ranges_inside_one_map_range_move_whole() {
+    let map = seed_to_soil();
+    assert_eq!(map.map_ranges(&[79..93]), [81..95]);
+    assert_eq!(map.map_ranges(&[55..68]), [57..70]);
+}
+
+#[test]
+fn ranges_split_where_map_ranges_start_and_end() {
+    let map = seed_to_soil();
+    assert_eq!(
+        map.pieces(&[40..105]),
+        [
+            Piece {
+                source: 40..50,
+                destination: 40..50,
+                matched: None
+            },
+            Piece {
+                source: 50..98,
+                destination: 52..100,
+                matched: Some(1)
+            },
+            Piece {
+                source: 98..100,
+                destination: 50..52,
+                matched: Some(0)
+            },
+            Piece {
+                source: 100..105,
+                destination: 100..105,
+                matched: None
+            },
+        ]
+    );
+    assert_eq!(map.map_ranges(&[40..105]), [40..105]);
+    assert_eq!(map.map_ranges(&[97..99]), [50..51, 99..100]);
+}
+
+#[test]
+fn split_ranges_agree_with_single_values() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    let locations = almanac
+        .convert_ranges("seed", "location", &[0..100])
+        .unwrap();
+    for seed in 0..100 {
+        let location = almanac.convert("seed", "location", seed).unwrap();
+        assert!(
+            locations.iter().any(|range| range.contains(&location)),
+            "seed {seed} lands on {location}"
+        );
+    }
+    let covered: u64 = locations.iter().map(|range| range.end - range.start).sum();
+    assert_eq!(covered, 100);
+}
+
+#[test]
+fn first_map_range_wins_where_ranges_overlap() {
+    let map = ToMap {
+        ranges: vec![
+            ToMapRange {
+                destination_range_start: 10,
+                source_range_start: 0,
+                range_length: 5,
+            },
+            ToMapRange {
+                destination_range_start: 20,
+                source_range_start: 3,
+                range_length: 5,
+            },
+        ],
+    };
+    assert_eq!(map.source_to_dest(4), 14);
+    assert_eq!(map.map_ranges(&[0..8]), [10..15, 22..25]);
+}
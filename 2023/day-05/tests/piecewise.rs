@@ -0,0 +1,125 @@
+#![allow(clippy::single_range_in_vec_init)]
+
+use day_5::{
+    almanac::{Almanac, ToMap, ToMapRange},
+    piecewise::Segment,
+};
+
+const SAMPLE: &str = include_str!("../src/bin/sample.txt");
+
+fn range(destination_range_start: u64, source_range_start: u64, range_length: u64) -> ToMapRange {
+    ToMapRange {
+        destination_range_start,
+        source_range_start,
+        range_length,
+    }
+}
+
+#[test]
+fn segments_tile_every_input() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    assert_eq!(
+        almanac.maps[0].map.segments(),
+        [
+            Segment {
+                source: 0..50,
+                offset: 0
+            },
+            Segment {
+                source: 50..98,
+                offset: 2
+            },
+            Segment {
+                source: 98..100,
+                offset: -48
+            },
+            Segment {
+                source: 100..u64::MAX,
+                offset: 0
+            },
+        ]
+    );
+}
+
+#[test]
+fn normalized_maps_are_sorted_and_drop_identity_ranges() {
+    let map = ToMap {
+        ranges: vec![range(20, 10, 5), range(15, 5, 5), range(30, 30, 10)],
+    };
+    assert_eq!(map.normalized().ranges, [range(15, 5, 10)]);
+    assert_eq!(map.normalized().normalized(), map.normalized());
+}
+
+#[test]
+fn composed_map_matches_the_chain() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    let composed = almanac.compose("seed", "location").unwrap();
+    for seed in 0..120 {
+        assert_eq!(
+            composed.source_to_dest(seed),
+            almanac.convert("seed", "location", seed).unwrap(),
+            "seed {seed}"
+        );
+    }
+    let lowest = composed.map_ranges(&[79..93, 55..68])[0].start;
+    assert_eq!(lowest, 46);
+    assert_eq!(almanac.compose("soil", "soil").unwrap().ranges, []);
+}
+
+#[test]
+fn sample_maps_invert() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    let inverse = almanac
+        .compose("seed", "location")
+        .unwrap()
+        .inverse()
+        .unwrap();
+    assert_eq!(inverse.source_to_dest(35), 13);
+    assert_eq!(inverse.source_to_dest(46), 82);
+    for map in &almanac.maps {
+        let inverse = map.map.inverse().unwrap();
+        for value in 0..120 {
+            assert_eq!(inverse.source_to_dest(map.map.source_to_dest(value)), value);
+        }
+    }
+}
+
+#[test]
+fn maps_that_merge_values_do_not_invert() {
+    let onto_identity = ToMap {
+        ranges: vec![range(10, 0, 5)],
+    };
+    assert_eq!(onto_identity.inverse(), None);
+    let onto_each_other = ToMap {
+        ranges: vec![range(0, 10, 5), range(0, 20, 5)],
+    };
+    assert_eq!(onto_each_other.inverse(), None);
+}
+
+#[test]
+fn preimage_finds_the_seeds_for_a_location() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    assert_eq!(
+        almanac.preimage("seed", "location", &[35..36]).unwrap(),
+        [13..14]
+    );
+    let seeds = almanac.preimage("seed", "location", &[0..47]).unwrap();
+    assert!(seeds.iter().any(|range| range.contains(&82)));
+    let merged = ToMap {
+        ranges: vec![range(0, 10, 5), range(0, 20, 5)],
+    };
+    assert_eq!(merged.preimage(&[1..2]), [1..2, 11..12, 21..22]);
+}
+
+#[test]
+fn display_lists_each_moved_stretch() {
+    let almanac = Almanac::parse(SAMPLE).unwrap();
+    let lines: Vec<String> = almanac.maps[0]
+        .map
+        .normalized()
+        .to_string()
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect();
+    assert_eq!(lines, ["50..98 -> 52..100 (+2)", "98..100 -> 50..52 (-48)"]);
+}
@@ -0,0 +1,87 @@
+use aoc_utils::render::{Color, Frame, Renderer, Style};
+
+use crate::{physics::Physics, race::Race};
+
+const BAR: char = '█';
+const RECORD: char = '┊';
+const OVERFLOW: char = '»';
+
+/// One row per bucket of hold times, up to `max_rows` rows, with a bar
+/// `bar_width` cells wide at the best distance. Bars are green where the
+/// bucket holds a winning hold, grey otherwise; the dotted column is the
+/// record, or a `»` in the last column when the record is off the chart.
+pub fn chart(race: &Race, physics: &Physics, max_rows: usize, bar_width: usize) -> Frame {
+    let outcome = physics.solve(race);
+    let holds = u128::from(race.time) + 1;
+    let rows = holds.min(max_rows.max(1) as u128) as u64;
+    let scale = |distance: u128| {
+        if outcome.best_distance == 0 {
+            0
+        } else {
+            (distance as f64 / outcome.best_distance as f64 * bar_width as f64).round() as usize
+        }
+    };
+    // An unwinnable race's record can be far past the best distance, so it
+    // gets a marker at the edge rather than a line that long.
+    let (record_column, record_mark) = match scale(u128::from(race.record_distance)) {
+        column if column > bar_width => (bar_width, OVERFLOW),
+        column => (column, RECORD),
+    };
+
+    let buckets: Vec<(u64, u64)> = (0..rows)
+        .map(|row| {
+            let start = u128::from(row) * holds / u128::from(rows);
+            let end = (u128::from(row) + 1) * holds / u128::from(rows);
+            (start as u64, (end - 1) as u64)
+        })
+        .collect();
+    let label_width = buckets
+        .iter()
+        .map(|(start, end)| label(*start, *end).len())
+        .max()
+        .unwrap_or_default();
+
+    let mut lines: Vec<Vec<char>> = vec![];
+    let mut winning = vec![];
+    let mut losing = vec![];
+    let mut record = vec![];
+    for (row, (start, end)) in buckets.into_iter().enumerate() {
+        // Distances rise to the optimal hold and fall after it, so the best
+        // hold in a bucket is the one closest to the optimum.
+        let closest = outcome.optimal_hold.clamp(start, end);
+        let distance = physics.distance(race, closest);
+        let bar = scale(distance);
+        let mut line: Vec<char> = format!("{:>label_width$} │", label(start, end))
+            .chars()
+            .collect();
+        let offset = line.len();
+        line.extend(std::iter::repeat_n(BAR, bar));
+        line.resize(offset + bar_width + 1, ' ');
+        if line[offset + record_column] == ' ' {
+            line[offset + record_column] = record_mark;
+        }
+        record.push((row, offset + record_column));
+        let cells = (offset..offset + bar).map(|col| (row, col));
+        if distance > u128::from(race.record_distance) {
+            winning.extend(cells);
+        } else {
+            losing.extend(cells);
+        }
+        lines.push(line);
+    }
+
+    Renderer::new()
+        .highlight_all(record, Style::fg(Color::Yellow))
+        .highlight_all(losing, Style::fg(Color::BrightBlack))
+        .highlight_all(winning, Style::fg(Color::Green))
+        .render(&lines)
+        .with_title(&format!("{race}: {outcome}"))
+}
+
+fn label(start: u64, end: u64) -> String {
+    if start == end {
+        start.to_string()
+    } else {
+        format!("{start}-{end}")
+    }
+}
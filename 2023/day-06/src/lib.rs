@@ -0,0 +1,5 @@
+pub mod chart;
+pub mod options;
+pub mod physics;
+pub mod race;
+pub mod sheet;
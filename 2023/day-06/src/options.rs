@@ -0,0 +1,56 @@
+use aoc_utils::render::Frame;
+
+use crate::{
+    chart,
+    physics::{Outcome, Physics},
+    race::Race,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    pub physics: Physics,
+    pub chart: bool,
+}
+
+impl Options {
+    /// `[--acceleration N] [--speed-cap N] [--charge-cost MS] [--chart]`. Without any
+    /// physics flags boats behave as in the puzzle.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut number = || -> Result<u64, String> {
+                args.next()
+                    .ok_or(format!("{arg} needs a value"))?
+                    .parse()
+                    .map_err(|error| format!("{arg}: {error}"))
+            };
+            match arg.as_str() {
+                "--acceleration" => options.physics.acceleration = number()?,
+                "--speed-cap" => options.physics.speed_cap = Some(number()?),
+                "--charge-cost" => options.physics.charge_cost = number()?,
+                "--chart" => options.chart = true,
+                other => return Err(format!("Unknown argument {other}")),
+            }
+        }
+        Ok(options)
+    }
+
+    /// The race's outcome. Puzzle physics count the winning holds with the
+    /// closed form; anything else goes through the general solver.
+    pub fn solve(&self, race: &Race) -> Outcome {
+        if self.physics == Physics::default() {
+            return Outcome {
+                ways_to_win: race.compute_number_of_ways_to_win(),
+                ..self.physics.best_hold(race)
+            };
+        }
+        self.physics.solve(race)
+    }
+
+    /// The race's distance chart, if `--chart` asked for one.
+    pub fn chart(&self, race: &Race) -> Option<Frame> {
+        self.chart
+            .then(|| chart::chart(race, &self.physics, 30, 60))
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+
+use crate::race::Race;
+
+/// How a hold turns into distance. Holding for `h` ms gives a speed of
+/// `acceleration * h`, capped at `speed_cap`; after release the boat spends
+/// `charge_cost` ms charging before it moves for whatever time is left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Physics {
+    pub acceleration: u64,
+    pub speed_cap: Option<u64>,
+    pub charge_cost: u64,
+}
+
+impl Default for Physics {
+    /// The puzzle's boats: one mm/ms per ms held, no cap, no charging.
+    fn default() -> Self {
+        Physics {
+            acceleration: 1,
+            speed_cap: None,
+            charge_cost: 0,
+        }
+    }
+}
+
+/// `best_distance` saturates at `u128::MAX`; `margin` is how far the best
+/// hold beats the record by, negative if no hold does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub ways_to_win: u64,
+    pub optimal_hold: u64,
+    pub best_distance: u128,
+    pub margin: i128,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} winning holds, best hold {} ms goes {} mm ({:+} mm on the record)",
+            self.ways_to_win, self.optimal_hold, self.best_distance, self.margin
+        )
+    }
+}
+
+impl Physics {
+    pub fn speed(&self, button_held_for: u64) -> u128 {
+        let speed = u128::from(self.acceleration) * u128::from(button_held_for);
+        match self.speed_cap {
+            Some(cap) => speed.min(u128::from(cap)),
+            None => speed,
+        }
+    }
+
+    /// Saturates at `u128::MAX`, which still beats any `u64` record.
+    pub fn distance(&self, race: &Race, button_held_for: u64) -> u128 {
+        let moving = race
+            .time
+            .saturating_sub(button_held_for)
+            .saturating_sub(self.charge_cost);
+        self.speed(button_held_for)
+            .saturating_mul(u128::from(moving))
+    }
+
+    /// Distance rises with the hold until the speed caps out or half the
+    /// remaining time is spent holding, then falls, so the best hold is at
+    /// one of those turning points.
+    pub fn optimal_hold(&self, race: &Race) -> u64 {
+        let usable = race.time.saturating_sub(self.charge_cost);
+        let mut candidates = vec![usable / 2, usable.div_ceil(2)];
+        if let Some(cap) = self.speed_cap {
+            if self.acceleration > 0 {
+                let capped_at = cap.div_ceil(self.acceleration);
+                candidates.extend([capped_at.saturating_sub(1), capped_at]);
+            }
+        }
+        candidates
+            .into_iter()
+            .map(|hold| hold.min(race.time))
+            .max_by_key(|hold| (self.distance(race, *hold), std::cmp::Reverse(*hold)))
+            .unwrap_or_default()
+    }
+
+    /// The optimal hold, how far it goes and how that compares with the
+    /// record, with `ways_to_win` left at 0 for the caller to count.
+    pub fn best_hold(&self, race: &Race) -> Outcome {
+        let optimal_hold = self.optimal_hold(race);
+        let best_distance = self.distance(race, optimal_hold);
+        let margin = i128::try_from(best_distance)
+            .unwrap_or(i128::MAX)
+            .saturating_sub(i128::from(race.record_distance));
+        Outcome {
+            ways_to_win: 0,
+            optimal_hold,
+            best_distance,
+            margin,
+        }
+    }
+
+    /// Winning holds form one run around the optimal hold, so binary searches
+    /// on either side find its ends in `O(log time)` distance evaluations.
+    pub fn solve(&self, race: &Race) -> Outcome {
+        let record = u128::from(race.record_distance);
+        let best = self.best_hold(race);
+        if best.best_distance <= record {
+            return best;
+        }
+
+        let wins = |hold: u64| self.distance(race, hold) > record;
+        // First winning hold in 0..=optimal: distances only rise up to it.
+        let (mut low, mut high) = (0, best.optimal_hold);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if wins(middle) {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        let first = low;
+        // Last winning hold in optimal..=time: distances only fall after it.
+        let (mut low, mut high) = (best.optimal_hold, race.time);
+        while low < high {
+            let middle = low + (high - low).div_ceil(2);
+            if wins(middle) {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+        Outcome {
+            ways_to_win: low - first + 1,
+            ..best
+        }
+    }
+
+    /// Tries every hold time; for checking `solve` on small races.
+    pub fn solve_brute_force(&self, race: &Race) -> u64 {
+        (0..=race.time)
+            .filter(|hold| self.distance(race, *hold) > u128::from(race.record_distance))
+            .count() as u64
+    }
+}
+
+impl Race {
+    pub fn solve_with(&self, physics: &Physics) -> Outcome {
+        physics.solve(self)
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    pub record_distance: u64,
+}
+
+impl fmt::Display for Race {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ms race, record {} mm",
+            self.time, self.record_distance
+        )
+    }
+}
+
+impl Race {
+    pub fn new(time: u64, record_distance: u64) -> Self {
+        Race {
+            time,
+            record_distance,
+        }
+    }
+
+    pub fn convert_to_races(times: Vec<u64>, record_distances: Vec<u64>) -> Vec<Race> {
+        times
+            .iter()
+            .zip(record_distances)
+            .fold(vec![], |mut acc, (time, record_distance)| {
+                acc.push(Race {
+                    time: *time,
+                    record_distance,
+                });
+                acc
+            })
+    }
+
+    /// `hold * (time - hold)`, which needs up to 126 bits for a `u64` time.
+    /// Holding for longer than the race lasts goes nowhere.
+    pub fn compute_distance_from_button_hold(&self, button_held_for: u64) -> u128 {
+        let speed = u128::from(button_held_for);
+        let remaining_time = u128::from(self.time.saturating_sub(button_held_for));
+
+        speed * remaining_time
+    }
+
+    fn wins(&self, button_held_for: u64) -> bool {
+        self.compute_distance_from_button_hold(button_held_for) > u128::from(self.record_distance)
+    }
+
+    /// Hold times `h` win when `h * (time - h) > record`, i.e. strictly between
+    /// the roots of `h² - time·h + record`. The integer square root of the
+    /// discriminant puts the first winning hold within a step of
+    /// `(time - √disc) / 2`; checking its neighbours exactly settles the cases
+    /// where a root is itself an integer. Winning holds are symmetric around
+    /// `time / 2`, so the last one is `time - first`.
+    pub fn compute_number_of_ways_to_win(&self) -> u64 {
+        let time = u128::from(self.time);
+        let discriminant = match (time * time).checked_sub(4 * u128::from(self.record_distance)) {
+            Some(discriminant) if discriminant > 0 => discriminant,
+            _ => return 0,
+        };
+        let root = discriminant.isqrt();
+        let mut first =
+            u64::try_from((time - root.min(time)) / 2).expect("half of a u64 time fits in a u64");
+        while first > 0 && self.wins(first - 1) {
+            first -= 1;
+        }
+        while first <= self.time / 2 && !self.wins(first) {
+            first += 1;
+        }
+        if first > self.time / 2 {
+            return 0;
+        }
+        self.time - 2 * first + 1
+    }
+
+    /// Tries every hold time; for checking the closed form on small races.
+    pub fn compute_number_of_ways_to_win_brute_force(&self) -> u64 {
+        (0..self.time)
+            .filter(|button_held_for| self.wins(*button_held_for))
+            .count() as u64
+    }
+}
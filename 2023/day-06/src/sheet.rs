@@ -0,0 +1,170 @@
+use std::{error, fmt};
+
+use nom::{
+    bytes::complete::take_till1,
+    character::complete::{char, digit1, space0},
+    combinator::{all_consuming, map},
+    multi::many1,
+    sequence::{preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::race::Race;
+
+const TIME: &str = "Time";
+const DISTANCE: &str = "Distance";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SheetError {
+    Syntax {
+        line: usize,
+    },
+    DuplicateRow {
+        label: String,
+    },
+    MissingRow {
+        label: String,
+    },
+    ColumnCount {
+        label: String,
+        expected: usize,
+        found: usize,
+    },
+    TooLarge {
+        label: String,
+    },
+}
+
+impl fmt::Display for SheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SheetError::Syntax { line } => {
+                write!(f, "line {line}: expected `Label: number number ...`")
+            }
+            SheetError::DuplicateRow { label } => write!(f, "{label} row appears more than once"),
+            SheetError::MissingRow { label } => write!(f, "no {label} row"),
+            SheetError::ColumnCount {
+                label,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{label} row has {found} columns but the sheet has {expected}"
+            ),
+            SheetError::TooLarge { label } => write!(f, "{label} row has a number over u64::MAX"),
+        }
+    }
+}
+
+impl error::Error for SheetError {}
+
+/// One `Label: n n n` line. `kerned` reads all of the row's digits as a
+/// single number, and is `None` when that number does not fit a `u64`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub label: String,
+    pub values: Vec<u64>,
+    pub kerned: Option<u64>,
+}
+
+/// Every labelled row of the sheet, in input order. All rows have the same
+/// number of columns, and `Time` and `Distance` rows are always present.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaceSheet {
+    pub rows: Vec<Row>,
+}
+
+fn parse_line(input: &str) -> IResult<&str, (&str, Vec<&str>)> {
+    all_consuming(tuple((
+        map(take_till1(|char| char == ':'), str::trim),
+        terminated(preceded(char(':'), many1(preceded(space0, digit1))), space0),
+    )))(input)
+}
+
+impl RaceSheet {
+    /// Blank lines are skipped; any other line is a row. Extra rows beside
+    /// `Time` and `Distance` are kept, and must have as many columns.
+    pub fn parse(input: &str) -> Result<Self, SheetError> {
+        let mut rows: Vec<Row> = vec![];
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                continue;
+            }
+            let (_, (label, fields)) =
+                parse_line(line).map_err(|_| SheetError::Syntax { line: index + 1 })?;
+            if label.is_empty() {
+                return Err(SheetError::Syntax { line: index + 1 });
+            }
+            if rows.iter().any(|row| row.label == label) {
+                return Err(SheetError::DuplicateRow {
+                    label: label.to_owned(),
+                });
+            }
+            let values = fields
+                .iter()
+                .map(|field| field.parse())
+                .collect::<Result<_, _>>()
+                .map_err(|_| SheetError::TooLarge {
+                    label: label.to_owned(),
+                })?;
+            rows.push(Row {
+                label: label.to_owned(),
+                values,
+                kerned: aoc_utils::bytes::digits_to_unsigned(fields.concat().as_bytes()),
+            });
+        }
+
+        let sheet = RaceSheet { rows };
+        for label in [TIME, DISTANCE] {
+            sheet.row(label)?;
+        }
+        let expected = sheet.rows[0].values.len();
+        if let Some(row) = sheet.rows.iter().find(|row| row.values.len() != expected) {
+            return Err(SheetError::ColumnCount {
+                label: row.label.clone(),
+                expected,
+                found: row.values.len(),
+            });
+        }
+        Ok(sheet)
+    }
+
+    pub fn row(&self, label: &str) -> Result<&Row, SheetError> {
+        self.rows
+            .iter()
+            .find(|row| row.label == label)
+            .ok_or_else(|| SheetError::MissingRow {
+                label: label.to_owned(),
+            })
+    }
+
+    pub fn columns(&self) -> usize {
+        self.rows[0].values.len()
+    }
+
+    /// Column reading: one race per column.
+    pub fn races(&self) -> Vec<Race> {
+        let (time, distance) = self.rows_for_races();
+        Race::convert_to_races(time.values.clone(), distance.values.clone())
+    }
+
+    /// Kerned reading: each row's digits run together into one race.
+    pub fn kerned_race(&self) -> Result<Race, SheetError> {
+        let (time, distance) = self.rows_for_races();
+        let kerned = |row: &Row| {
+            row.kerned.ok_or_else(|| SheetError::TooLarge {
+                label: row.label.clone(),
+            })
+        };
+        Ok(Race {
+            time: kerned(time)?,
+            record_distance: kerned(distance)?,
+        })
+    }
+
+    fn rows_for_races(&self) -> (&Row, &Row) {
+        let row = |label| self.row(label).expect("parse checks for the race rows");
+        (row(TIME), row(DISTANCE))
+    }
+}
@@ -0,0 +1,47 @@
+use day_6::{chart::chart, physics::Physics, race::Race};
+
+// Characters on screen, skipping colour escape codes.
+fn visible(line: &str) -> String {
+    let mut visible = String::new();
+    let mut in_escape = false;
+    for char in line.chars() {
+        match (in_escape, char) {
+            (false, '\x1b') => in_escape = true,
+            (true, 'm') => in_escape = false,
+            (true, _) => (),
+            (false, char) => visible.push(char),
+        }
+    }
+    visible
+}
+
+#[test]
+fn record_column_sits_inside_the_bars() {
+    let race = Race::new(7, 9);
+    let frame = chart(&race, &Physics::default(), 30, 20);
+    let rows: Vec<String> = frame.lines[1..].iter().map(|line| visible(line)).collect();
+    assert_eq!(rows.len(), 8);
+    // Label, " │", 20 bar cells and the record column's spare cell.
+    assert!(rows.iter().all(|row| row.chars().count() == 1 + 2 + 21));
+    assert!(rows.iter().any(|row| row.contains('┊')));
+    assert!(!rows.iter().any(|row| row.contains('»')));
+}
+
+#[test]
+fn unwinnable_record_is_marked_at_the_edge() {
+    let race = Race::new(40_000_000, 200_000_000_000_000);
+    let physics = Physics {
+        speed_cap: Some(1),
+        ..Physics::default()
+    };
+    assert_eq!(physics.solve(&race).ways_to_win, 0);
+    let frame = chart(&race, &physics, 30, 60);
+    let rows: Vec<String> = frame.lines[1..].iter().map(|line| visible(line)).collect();
+    assert_eq!(rows.len(), 30);
+    let label_width = "39999999-40000000".len();
+    assert!(rows
+        .iter()
+        .all(|row| row.chars().count() == label_width + 2 + 61));
+    assert!(rows.iter().all(|row| row.ends_with('»')));
+    assert!(!rows.iter().any(|row| row.contains('┊')));
+}
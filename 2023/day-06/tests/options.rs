@@ -0,0 +1,71 @@
+use day_6::{chart::chart, options::Options, physics::Physics, race::Race};
+
+#[test]
+fn puzzle_physics_use_the_closed_form() {
+    let options = Options::parse(vec![]).unwrap();
+    assert_eq!(options, Options::default());
+    let races = [
+        Race::new(7, 9),
+        Race::new(15, 40),
+        Race::new(30, 200),
+        Race::new(71530, 940200),
+    ];
+    let ways: Vec<u64> = races
+        .iter()
+        .map(|race| options.solve(race).ways_to_win)
+        .collect();
+    assert_eq!(ways, [4, 8, 9, 71503]);
+    for race in &races {
+        assert_eq!(options.solve(race), options.physics.solve(race));
+    }
+}
+
+#[test]
+fn puzzle_physics_report_the_optimal_hold_and_margin() {
+    let options = Options::default();
+    let outcome = options.solve(&Race::new(7, 9));
+    assert_eq!(
+        (outcome.optimal_hold, outcome.best_distance, outcome.margin),
+        (3, 12, 3)
+    );
+    assert_eq!(
+        options.solve(&Race::new(30, 200)).to_string(),
+        "9 winning holds, best hold 15 ms goes 225 mm (+25 mm on the record)"
+    );
+}
+
+#[test]
+fn charts_only_with_the_flag() {
+    let race = Race::new(7, 9);
+    assert!(Options::default().chart(&race).is_none());
+    let options = Options::parse(["--chart".to_owned()]).unwrap();
+    assert!(options.chart);
+    assert_eq!(
+        options.chart(&race).map(|frame| frame.to_string()),
+        Some(chart(&race, &Physics::default(), 30, 60).to_string())
+    );
+}
+
+#[test]
+fn other_physics_use_the_general_solver() {
+    let options =
+        Options::parse(["--speed-cap", "3", "--charge-cost", "1"].map(str::to_owned)).unwrap();
+    assert_eq!(
+        options.physics,
+        Physics {
+            speed_cap: Some(3),
+            charge_cost: 1,
+            ..Physics::default()
+        }
+    );
+    for race in [Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)] {
+        assert_eq!(
+            options.solve(&race).ways_to_win,
+            options.physics.solve_brute_force(&race)
+        );
+    }
+    assert_eq!(
+        Options::parse(["--speed-cap".to_owned()]),
+        Err("--speed-cap needs a value".to_owned())
+    );
+}
@@ -0,0 +1,93 @@
+use aoc_utils::rng::Rng;
+use day_6::{physics::Physics, race::Race};
+
+fn physics(acceleration: u64, speed_cap: Option<u64>, charge_cost: u64) -> Physics {
+    Physics {
+        acceleration,
+        speed_cap,
+        charge_cost,
+    }
+}
+
+#[test]
+fn default_physics_matches_the_closed_form() {
+    let mut rng = Rng::new(0xbb67_ae85_84ca_a73b);
+    for _ in 0..2_000 {
+        let time = rng.next_u64() % 1_000_000;
+        let record = rng.next_u64() % ((time / 2) * (time - time / 2) + 2);
+        let race = Race::new(time, record);
+        assert_eq!(
+            Physics::default().solve(&race).ways_to_win,
+            race.compute_number_of_ways_to_win(),
+            "{race:?}"
+        );
+    }
+}
+
+#[test]
+fn sample_outcome() {
+    let outcome = Physics::default().solve(&Race::new(7, 9));
+    assert_eq!(outcome.ways_to_win, 4);
+    // Holds 3 and 4 both go 12 mm; the shorter hold is reported.
+    assert_eq!(outcome.optimal_hold, 3);
+    assert_eq!(outcome.best_distance, 12);
+    assert_eq!(outcome.margin, 3);
+}
+
+#[test]
+fn speed_cap_moves_the_optimal_hold() {
+    // Capped at 4 mm/ms, holding 4 leaves 26 ms to go 104 mm; holding longer
+    // gains no speed and loses time.
+    let outcome = physics(1, Some(4), 0).solve(&Race::new(30, 100));
+    assert_eq!(outcome.optimal_hold, 4);
+    assert_eq!(outcome.best_distance, 104);
+    assert_eq!(outcome.ways_to_win, 1);
+}
+
+#[test]
+fn charge_cost_and_acceleration() {
+    // Holding h then charging 2 ms goes 3h * (10 - h - 2).
+    let physics = physics(3, None, 2);
+    assert_eq!(physics.distance(&Race::new(10, 0), 4), 48);
+    let outcome = physics.solve(&Race::new(10, 40));
+    assert_eq!(outcome.optimal_hold, 4);
+    assert_eq!(outcome.ways_to_win, 3);
+}
+
+#[test]
+fn lost_races_report_a_negative_margin() {
+    let outcome = physics(1, None, 10).solve(&Race::new(10, 5));
+    assert_eq!(outcome.ways_to_win, 0);
+    assert_eq!(outcome.best_distance, 0);
+    assert_eq!(outcome.margin, -5);
+}
+
+#[test]
+fn matches_brute_force_with_random_physics() {
+    let mut rng = Rng::new(0x3c6e_f372_fe94_f82b);
+    for _ in 0..5_000 {
+        let physics = physics(
+            rng.next_u64() % 5,
+            rng.next_u64()
+                .is_multiple_of(2)
+                .then(|| rng.next_u64() % 60),
+            rng.next_u64() % 20,
+        );
+        let time = rng.next_u64() % 200;
+        let best = physics.distance(
+            &Race::new(time, 0),
+            physics.optimal_hold(&Race::new(time, 0)),
+        );
+        let record = match rng.next_u64() % 4 {
+            0 => best as u64,
+            1 => (best as u64).saturating_sub(rng.next_u64() % 3),
+            _ => rng.next_u64() % (best as u64 + 2),
+        };
+        let race = Race::new(time, record);
+        assert_eq!(
+            physics.solve(&race).ways_to_win,
+            physics.solve_brute_force(&race),
+            "{physics:?} {race:?}"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use day_6::{
+    race::Race,
+    sheet::{RaceSheet, SheetError},
+};
+
+const SAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+#[test]
+fn sample_gives_both_readings() {
+    let sheet = RaceSheet::parse(SAMPLE).unwrap();
+    assert_eq!(sheet.columns(), 3);
+    assert_eq!(
+        sheet.races(),
+        vec![Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)]
+    );
+    assert_eq!(sheet.kerned_race(), Ok(Race::new(71530, 940200)));
+}
+
+#[test]
+fn any_number_of_columns_and_crlf() {
+    let sheet = RaceSheet::parse("Time: 1 2 3 4 5\r\nDistance: 6 7 8 9 10\r\n").unwrap();
+    assert_eq!(sheet.races().len(), 5);
+    assert_eq!(sheet.kerned_race(), Ok(Race::new(12345, 678910)));
+    let sheet = RaceSheet::parse("Distance: 9\nTime: 7").unwrap();
+    assert_eq!(sheet.races(), vec![Race::new(7, 9)]);
+}
+
+#[test]
+fn extra_labelled_rows_are_kept() {
+    let sheet = RaceSheet::parse(&format!("{SAMPLE}\nWind speed:  1  0  2\n")).unwrap();
+    let wind = sheet.row("Wind speed").unwrap();
+    assert_eq!(wind.values, vec![1, 0, 2]);
+    assert_eq!(wind.kerned, Some(102));
+    assert_eq!(sheet.races().len(), 3);
+}
+
+#[test]
+fn mismatched_columns_are_errors() {
+    assert_eq!(
+        RaceSheet::parse("Time: 7 15 30\nDistance: 9 40\n"),
+        Err(SheetError::ColumnCount {
+            label: "Distance".to_owned(),
+            expected: 3,
+            found: 2,
+        })
+    );
+    assert!(matches!(
+        RaceSheet::parse(&format!("{SAMPLE}Wind: 1\n")),
+        Err(SheetError::ColumnCount { found: 1, .. })
+    ));
+}
+
+#[test]
+fn malformed_sheets() {
+    assert_eq!(
+        RaceSheet::parse("Time: 7\n"),
+        Err(SheetError::MissingRow {
+            label: "Distance".to_owned()
+        })
+    );
+    assert_eq!(
+        RaceSheet::parse("Time: 7\nTime: 8\nDistance: 9\n"),
+        Err(SheetError::DuplicateRow {
+            label: "Time".to_owned()
+        })
+    );
+    assert_eq!(
+        RaceSheet::parse("Time: 7\nDistance: 9x\n"),
+        Err(SheetError::Syntax { line: 2 })
+    );
+    assert_eq!(
+        RaceSheet::parse("Time: 7\nDistance:\n"),
+        Err(SheetError::Syntax { line: 2 })
+    );
+    assert_eq!(
+        RaceSheet::parse("Time: 7\nDistance: 99999999999999999999\n"),
+        Err(SheetError::TooLarge {
+            label: "Distance".to_owned()
+        })
+    );
+}
+
+#[test]
+fn kerned_overflow_still_reads_columns() {
+    let sheet = RaceSheet::parse("Time: 9999999999 9999999999\nDistance: 1 2\n").unwrap();
+    assert_eq!(sheet.races().len(), 2);
+    assert_eq!(
+        sheet.kerned_race(),
+        Err(SheetError::TooLarge {
+            label: "Time".to_owned()
+        })
+    );
+}
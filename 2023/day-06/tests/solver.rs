@@ -0,0 +1,68 @@
+use aoc_utils::rng::Rng;
+use day_6::race::Race;
+
+#[test]
+fn sample() {
+    let races = [Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)];
+    let ways: Vec<u64> = races
+        .iter()
+        .map(|race| race.compute_number_of_ways_to_win())
+        .collect();
+    assert_eq!(ways, vec![4, 8, 9]);
+    assert_eq!(
+        Race::new(71530, 940200).compute_number_of_ways_to_win(),
+        71503
+    );
+}
+
+#[test]
+fn integer_roots_do_not_win() {
+    // 10 * (30 - 10) = 200 ties the record at both roots.
+    assert_eq!(Race::new(30, 200).compute_number_of_ways_to_win(), 9);
+    // Only the vertex reaches the record, and a tie is not a win.
+    assert_eq!(Race::new(10, 25).compute_number_of_ways_to_win(), 0);
+    assert_eq!(Race::new(10, 24).compute_number_of_ways_to_win(), 1);
+}
+
+#[test]
+fn degenerate_races() {
+    assert_eq!(Race::new(0, 0).compute_number_of_ways_to_win(), 0);
+    assert_eq!(Race::new(1, 0).compute_number_of_ways_to_win(), 0);
+    assert_eq!(Race::new(2, 0).compute_number_of_ways_to_win(), 1);
+    assert_eq!(Race::new(5, u64::MAX).compute_number_of_ways_to_win(), 0);
+}
+
+#[test]
+fn huge_times_do_not_overflow() {
+    assert_eq!(
+        Race::new(u64::MAX, 0).compute_number_of_ways_to_win(),
+        u64::MAX - 1
+    );
+    // Holding 1 travels u64::MAX - 1, short of the record, so the winners
+    // are 2..=u64::MAX - 2.
+    assert_eq!(
+        Race::new(u64::MAX, u64::MAX).compute_number_of_ways_to_win(),
+        u64::MAX - 3
+    );
+}
+
+#[test]
+fn matches_brute_force_on_random_races() {
+    let mut rng = Rng::new(0x6a09_e667_f3bc_c908);
+    for _ in 0..5_000 {
+        let time = rng.next_u64() % 400;
+        let best = (time / 2) * (time - time / 2);
+        // Records around the best distance hit the no-win and vertex cases.
+        let record = match rng.next_u64() % 4 {
+            0 => best,
+            1 => best.saturating_sub(rng.next_u64() % 3),
+            _ => rng.next_u64() % (best + 2),
+        };
+        let race = Race::new(time, record);
+        assert_eq!(
+            race.compute_number_of_ways_to_win(),
+            race.compute_number_of_ways_to_win_brute_force(),
+            "{race:?}"
+        );
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 use nom::{
     bytes::complete::tag,
-    character::complete::{alpha1, line_ending, not_line_ending},
+    character::complete::{alpha1, line_ending},
     multi::separated_list1,
     sequence::{terminated, tuple},
     IResult,
@@ -45,14 +45,14 @@ impl<'a> NetworkDiagram<'a> {
     }
 }
 
-fn parse_node<'a>(input: &'a str) -> IResult<&str, (&'a str, Node<'a>)> {
+fn parse_node<'a>(input: &'a str) -> IResult<&'a str, (&'a str, Node<'a>)> {
     let (input, key) = terminated(alpha1, tag(" = "))(input)?;
     let (input, (_, left, _, right, _)) =
         tuple((tag("("), alpha1, tag(", "), alpha1, tag(")")))(input)?;
     Ok((input, (key, Node { left, right })))
 }
 
-fn parse_network_diagram<'a>(input: &'a str) -> IResult<&str, NetworkDiagram<'a>> {
+fn parse_network_diagram<'a>(input: &'a str) -> IResult<&'a str, NetworkDiagram<'a>> {
     let (input, instructions) = terminated(alpha1, tuple((line_ending, line_ending)))(input)?;
     let (input, nodes) = separated_list1(line_ending, parse_node)(input)?;
     let mut tree = BTreeMap::new();
@@ -1,5 +1,7 @@
 use std::collections::BTreeMap;
 
+use aoc_utils::cycle::{self, Cycle};
+
 use nom::{
     bytes::complete::tag,
     character::complete::{alpha1, alphanumeric1, line_ending},
@@ -28,98 +30,40 @@ struct NetworkDiagram<'a> {
 }
 
 impl<'a> NetworkDiagram<'a> {
-    fn find_exit(&mut self) -> usize {
-        let starting_nodes: Vec<Node<'a>> = self
-            .starting_node_keys
-            .iter()
-            .map(|starting_node_key| *self.tree.get(starting_node_key).unwrap())
-            .collect();
-        println!("{:?}", starting_nodes);
+    fn step(&self, (instruction_index, key): &(usize, &'a str)) -> (usize, &'a str) {
+        let node = self.tree.get(key).unwrap();
+        let next_key = match self.instructions[*instruction_index] {
+            Direction::Left => node.left,
+            Direction::Right => node.right,
+        };
+        ((instruction_index + 1) % self.instructions.len(), next_key)
+    }
 
-        let smallest_cycle_len: Vec<(usize, usize)> = starting_nodes
-            .iter()
-            .map(|starting_node| {
-                let mut first_end = (0, "");
-                let step_size = self
-                    .instructions
-                    .iter()
-                    .cycle()
-                    .enumerate()
-                    .scan(starting_node, |current_node, (index, instruction)| {
-                        let key;
-                        *current_node = match instruction {
-                            Direction::Left => {
-                                key = current_node.left;
-                                self.tree.get(current_node.left).unwrap()
-                            }
-                            Direction::Right => {
-                                key = current_node.right;
-                                self.tree.get(current_node.right).unwrap()
-                            }
-                        };
-                        if first_end.0 != 0 {
-                            (key != first_end.1).then_some(())
-                        } else {
-                            if key.chars().last() == Some('Z') {
-                                first_end.0 = index;
-                                first_end.1 = key;
-                            }
-                            Some(())
-                        }
-                    })
-                    .count();
-                (first_end.0, step_size)
-            })
-            .collect();
-        let smallest_cycle_len: Vec<usize> = smallest_cycle_len
+    fn find_exit(&self) -> Option<usize> {
+        let ghost_cycles: Vec<Cycle> = self
+            .starting_node_keys
             .iter()
-            .map(|(first_end, step_size)| {
-                let step = step_size - first_end - 1;
-                assert!(first_end == &step);
-                step_size + 1
+            .map(|starting_node_key| {
+                cycle::detect(
+                    (0, *starting_node_key),
+                    |state| self.step(state),
+                    |(_, key)| key.ends_with('Z'),
+                )
             })
             .collect();
-        println!("{:?}", smallest_cycle_len);
-        let largest_step =
-            smallest_cycle_len.iter().fold(
-                usize::MIN,
-                |acc, val| {
-                    if *val > acc {
-                        *val
-                    } else {
-                        acc
-                    }
-                },
-            );
-        vec![largest_step]
-            .iter()
-            .cycle()
-            .scan(usize::MIN, |acc, step_size| {
-                *acc = *acc + *step_size;
-                Some(*acc)
-            })
-            .take_while(|current_step_count| {
-                !smallest_cycle_len
-                    .iter()
-                    .map(|len| {
-                        let rem = current_step_count % *len;
-                        rem
-                    })
-                    .all(|len| len == 0)
-            })
-            .last()
-            .unwrap()
+
+        cycle::first_common_hit(&ghost_cycles)
     }
 }
 
-fn parse_node<'a>(input: &'a str) -> IResult<&str, (&'a str, Node<'a>)> {
+fn parse_node<'a>(input: &'a str) -> IResult<&'a str, (&'a str, Node<'a>)> {
     let (input, key) = terminated(alphanumeric1, tag(" = "))(input)?;
     let (input, (_, left, _, right, _)) =
         tuple((tag("("), alphanumeric1, tag(", "), alphanumeric1, tag(")")))(input)?;
     Ok((input, (key, Node { left, right })))
 }
 
-fn parse_instruction<'a>(input: &'a str) -> IResult<&str, Vec<Direction>> {
+fn parse_instruction(input: &str) -> IResult<&str, Vec<Direction>> {
     let (input, instructions) = terminated(alpha1, tuple((line_ending, line_ending)))(input)?;
     let instructions = instructions
         .chars()
@@ -132,7 +76,7 @@ fn parse_instruction<'a>(input: &'a str) -> IResult<&str, Vec<Direction>> {
     Ok((input, instructions))
 }
 
-fn parse_network_diagram<'a>(input: &'a str) -> IResult<&str, NetworkDiagram<'a>> {
+fn parse_network_diagram<'a>(input: &'a str) -> IResult<&'a str, NetworkDiagram<'a>> {
     let (input, instructions) = parse_instruction(input)?;
     let (input, nodes) = separated_list1(line_ending, parse_node)(input)?;
     let mut tree = BTreeMap::new();
@@ -148,7 +92,7 @@ fn parse_network_diagram<'a>(input: &'a str) -> IResult<&str, NetworkDiagram<'a>
             starting_node_keys: nodes
                 .iter()
                 .map(|(key, _)| *key)
-                .filter(|key| key.chars().last() == Some('A'))
+                .filter(|key| key.ends_with('A'))
                 .collect(),
         },
     ))
@@ -156,6 +100,9 @@ fn parse_network_diagram<'a>(input: &'a str) -> IResult<&str, NetworkDiagram<'a>
 
 fn main() {
     let input = include_str!("./input2.txt");
-    let (_input, mut network_diagram) = parse_network_diagram(input).expect("Should parse diagram");
-    println!("{:?}", network_diagram.find_exit());
+    let (_input, network_diagram) = parse_network_diagram(input).expect("Should parse diagram");
+    let result = network_diagram
+        .find_exit()
+        .expect("Ghosts should all reach an exit together");
+    println!("{result}");
 }
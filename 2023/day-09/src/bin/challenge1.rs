@@ -1,37 +1,20 @@
+use std::{env, process};
+
+use aoc_utils::memo::Memo;
+
 use nom::{
     character::complete::{i64, multispace1},
     multi::separated_list1,
     IResult,
 };
 
-fn find_diff(input: Vec<i64>) -> Vec<Vec<i64>> {
-    let mut all_zero = true;
-    let res: Vec<i64> = input
-        .iter()
-        .zip(input.iter().skip(1))
-        .map(|(a, b)| {
-            println!("a {a}, b {b}");
-            let res = b - a;
-            if res != 0 {
-                all_zero = false;
-            }
-            res
-        })
-        .collect();
-
-    let mut output: Vec<Vec<i64>> = vec![input.clone()];
-    if !all_zero {
-        let mut diff = find_diff(res);
-        output.append(&mut diff);
-    }
-    output
-}
-
-fn calculate_next_seq(input: Vec<i64>) -> i64 {
-    let diff = find_diff(input);
-    diff.iter().rev().fold(0, |acc, curr| {
-        let last = curr.last().unwrap();
-        acc + last
+fn calculate_next_seq(memo: &mut Memo<Vec<i64>, i64>, input: &[i64]) -> i64 {
+    memo.get_or_compute(input, |memo, input| {
+        if input.iter().all(|value| *value == 0) {
+            return 0;
+        }
+        let diff: Vec<i64> = input.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        input.last().unwrap() + calculate_next_seq(memo, &diff)
     })
 }
 
@@ -40,18 +23,40 @@ fn parse_vec(input: &str) -> IResult<&str, Vec<i64>> {
     Ok((input, u64vecvec))
 }
 
+// challenge1 [--stats]
+// --stats prints the memo cache counters on stderr.
+fn parse_args() -> Result<bool, String> {
+    let mut show_stats = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--stats" => show_stats = true,
+            other => return Err(format!("Unknown argument {other}")),
+        }
+    }
+    Ok(show_stats)
+}
+
 fn main() {
+    let show_stats = parse_args().unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(2)
+    });
     let input = include_str!("./input1.txt");
+    let mut memo = Memo::new();
     let res: i64 = input
         .split("\n")
         .filter(|str| !str.is_empty())
         .map(|input| {
             let (_, data) = parse_vec(input).expect("Should parse correctly");
-            println!("{data:?}");
-            let o = calculate_next_seq(data);
-            println!("{o:?}");
-            o
+            calculate_next_seq(&mut memo, &data)
         })
         .sum();
+    if show_stats {
+        let stats = memo.stats();
+        eprintln!(
+            "cache: {} hits, {} misses, {} evictions, {} entries",
+            stats.hits, stats.misses, stats.evictions, stats.len
+        );
+    }
     println!("{res}");
 }
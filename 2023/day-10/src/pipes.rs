@@ -0,0 +1,352 @@
+use aoc_utils::{
+    geometry::{self, Point},
+    render::{Color, Frame, GridView, Renderer, Style},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Tile {
+    VerticalPipe,
+    HorizontalPipe,
+    NorthToEastBend,
+    NorthToWestBend,
+    SouthToWestBend,
+    SouthToEastBend,
+    Ground,
+    StartingPosition,
+}
+
+impl Tile {
+    pub fn to_char(&self) -> char {
+        match self {
+            Self::VerticalPipe => '|',
+            Self::HorizontalPipe => '-',
+            Self::NorthToEastBend => 'L',
+            Self::NorthToWestBend => 'J',
+            Self::SouthToWestBend => '7',
+            Self::SouthToEastBend => 'F',
+            Self::Ground => '.',
+            Self::StartingPosition => 'S',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub row_index: usize,
+    pub col_index: usize,
+}
+
+#[derive(Debug)]
+pub struct PipeNetwork {
+    zone: Vec<Vec<Tile>>,
+    animal: Position,
+}
+
+impl GridView for PipeNetwork {
+    fn width(&self) -> usize {
+        self.zone.first().map(|row| row.len()).unwrap_or_default()
+    }
+
+    fn height(&self) -> usize {
+        self.zone.len()
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        self.zone[row][col].to_char()
+    }
+}
+
+impl PipeNetwork {
+    pub fn new(input: &str) -> Result<Self, &'static str> {
+        let mut position = None;
+        let zone = input
+            .split("\n")
+            .filter(|row| !row.is_empty())
+            .enumerate()
+            .map(|(row_index, rows)| {
+                rows.chars()
+                    .enumerate()
+                    .map(|(col_index, char)| {
+                        if char == 'S' {
+                            position = Some(Position {
+                                row_index,
+                                col_index,
+                            });
+                        }
+                        match char {
+                            '|' => Tile::VerticalPipe,
+                            '-' => Tile::HorizontalPipe,
+                            'L' => Tile::NorthToEastBend,
+                            'J' => Tile::NorthToWestBend,
+                            '7' => Tile::SouthToWestBend,
+                            'F' => Tile::SouthToEastBend,
+                            '.' => Tile::Ground,
+                            'S' => Tile::StartingPosition,
+                            _ => panic!(),
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        if let Some(animal) = position {
+            Ok(PipeNetwork { zone, animal })
+        } else {
+            Err("no animal")
+        }
+    }
+
+    /// The sketch with box-drawing pipes, the loop in yellow and the animal
+    /// in red.
+    pub fn render(&self) -> Frame {
+        let loop_positions = self.trace_loop();
+        let animal = self.animal;
+        let renderer = Renderer::new()
+            .glyphs([
+                ('|', '│'),
+                ('-', '─'),
+                ('L', '└'),
+                ('J', '┘'),
+                ('7', '┐'),
+                ('F', '┌'),
+                ('.', '·'),
+            ])
+            .style('.', Style::fg(Color::BrightBlack))
+            .highlight_all(
+                loop_positions
+                    .iter()
+                    .map(|position| (position.row_index, position.col_index)),
+                Style::fg(Color::Yellow),
+            )
+            .highlight(
+                animal.row_index,
+                animal.col_index,
+                Style::fg(Color::BrightRed).bold(),
+            );
+        renderer.render(self)
+    }
+
+    /// The next tile along the pipe at `position` when coming from
+    /// `prev_position`. From `S` it is the first neighbour whose pipe opens
+    /// back towards it.
+    pub fn follow_pipe(&self, position: Position, prev_position: Position) -> Position {
+        use Tile::*;
+        let tile = self
+            .zone
+            .get(position.row_index)
+            .unwrap()
+            .get(position.col_index)
+            .unwrap();
+        match tile {
+            VerticalPipe => {
+                if position.row_index > prev_position.row_index {
+                    Position {
+                        col_index: position.col_index,
+                        row_index: position.row_index + 1,
+                    }
+                } else {
+                    Position {
+                        col_index: position.col_index,
+                        row_index: position.row_index - 1,
+                    }
+                }
+            }
+            HorizontalPipe => {
+                if position.col_index > prev_position.col_index {
+                    Position {
+                        col_index: position.col_index + 1,
+                        row_index: position.row_index,
+                    }
+                } else {
+                    Position {
+                        col_index: position.col_index - 1,
+                        row_index: position.row_index,
+                    }
+                }
+            }
+            NorthToEastBend => {
+                // .X..
+                // .LX.
+                // ....
+                if position.col_index == prev_position.col_index {
+                    Position {
+                        col_index: position.col_index + 1,
+                        row_index: position.row_index,
+                    }
+                } else {
+                    Position {
+                        col_index: position.col_index,
+                        row_index: position.row_index - 1,
+                    }
+                }
+            }
+            NorthToWestBend => {
+                // ..X..
+                // .XJ..
+                // .....
+                if position.col_index == prev_position.col_index {
+                    Position {
+                        col_index: position.col_index - 1,
+                        row_index: position.row_index,
+                    }
+                } else {
+                    Position {
+                        col_index: position.col_index,
+                        row_index: position.row_index - 1,
+                    }
+                }
+            }
+            SouthToEastBend => {
+                // ....
+                // .FX.
+                // .X..
+                if position.col_index == prev_position.col_index {
+                    Position {
+                        col_index: position.col_index + 1,
+                        row_index: position.row_index,
+                    }
+                } else {
+                    Position {
+                        col_index: position.col_index,
+                        row_index: position.row_index + 1,
+                    }
+                }
+            }
+            SouthToWestBend => {
+                // ....
+                // .X7.
+                // ..X.
+                if position.col_index == prev_position.col_index {
+                    Position {
+                        col_index: position.col_index - 1,
+                        row_index: position.row_index,
+                    }
+                } else {
+                    Position {
+                        col_index: position.col_index,
+                        row_index: position.row_index + 1,
+                    }
+                }
+            }
+            StartingPosition => {
+                for row_offset in -1..=1 {
+                    for col_offset in -1..=1 {
+                        if let Ok(col_index) = usize::try_from(
+                            isize::try_from(position.col_index).unwrap() + col_offset,
+                        ) {
+                            if let Ok(row_index) = usize::try_from(
+                                isize::try_from(position.row_index).unwrap() + row_offset,
+                            ) {
+                                if let Some(row) = self.zone.get(row_index) {
+                                    let tile = row.get(col_index).unwrap_or(&Ground);
+                                    let position = Position {
+                                        col_index: usize::try_from(
+                                            isize::try_from(position.col_index).unwrap()
+                                                + col_offset,
+                                        )
+                                        .unwrap_or_default(),
+                                        row_index: usize::try_from(
+                                            isize::try_from(position.row_index).unwrap()
+                                                + row_offset,
+                                        )
+                                        .unwrap_or_default(),
+                                    };
+
+                                    match tile {
+                                        VerticalPipe if col_offset == 0 && row_offset != 0 => {
+                                            return position;
+                                        }
+                                        HorizontalPipe if row_offset == 0 && col_offset != 0 => {
+                                            return position;
+                                        }
+                                        NorthToEastBend => {
+                                            if row_offset == 1 && col_offset == 0 {
+                                                return position;
+                                            }
+
+                                            if row_offset == 0 && col_offset == -1 {
+                                                return position;
+                                            }
+                                        }
+                                        NorthToWestBend => {
+                                            if row_offset == 1 && col_offset == 0 {
+                                                return position;
+                                            }
+                                            if row_offset == 0 && col_offset == 1 {
+                                                return position;
+                                            }
+                                        }
+                                        SouthToWestBend => {
+                                            if row_offset == -1 && col_offset == 0 {
+                                                return position;
+                                            }
+                                            if row_offset == 0 && col_offset == 1 {
+                                                return position;
+                                            }
+                                        }
+                                        SouthToEastBend => {
+                                            if row_offset == -1 && col_offset == 0 {
+                                                return position;
+                                            }
+                                            if row_offset == 0 && col_offset == -1 {
+                                                return position;
+                                            }
+                                        }
+                                        _ => (),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                panic!()
+            }
+            Ground => position,
+        }
+    }
+
+    /// Every tile of the loop through `S`, in walking order from `S`.
+    pub fn trace_loop(&self) -> Vec<Position> {
+        let animal = self.animal;
+        let mut loop_positions = vec![animal];
+        let mut prev_position = animal;
+        let mut next_position = self.follow_pipe(animal, animal);
+        while self
+            .zone
+            .get(next_position.row_index)
+            .unwrap()
+            .get(next_position.col_index)
+            .unwrap()
+            != &Tile::StartingPosition
+        {
+            loop_positions.push(next_position);
+            let new_position = self.follow_pipe(next_position, prev_position);
+            prev_position = next_position;
+            next_position = new_position;
+        }
+
+        loop_positions
+    }
+
+    pub fn animal(&self) -> Position {
+        self.animal
+    }
+
+    /// The loop as a polygon with `x` along a row and `y` down the sketch.
+    pub fn polygon(&self) -> Vec<Point> {
+        self.trace_loop()
+            .iter()
+            .map(|position| Point::from((position.col_index, position.row_index)))
+            .collect()
+    }
+
+    /// Steps along the loop to the tile farthest from `S` either way round.
+    pub fn find_furthest_point(&self) -> usize {
+        self.trace_loop().len() / 2
+    }
+
+    /// Tiles inside the loop, by Pick's theorem on the loop's polygon.
+    pub fn count_enclosed_tiles(&self) -> u64 {
+        geometry::interior_points(&self.polygon()).expect("Should be a closed loop")
+    }
+}
@@ -0,0 +1,141 @@
+use aoc_utils::geometry;
+use day_10::pipes::{PipeNetwork, Position};
+
+// The loops from the README. Junk pipes off the loop are left in
+// so tracing has to follow the connections from `S`.
+const SQUARE: &str = "\
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+";
+
+const WINDING: &str = "\
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+";
+
+const ENCLOSED: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+const SQUEEZED: &str = "\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+";
+
+const LARGER: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+const JUNK: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+fn position(row_index: usize, col_index: usize) -> Position {
+    Position {
+        row_index,
+        col_index,
+    }
+}
+
+#[test]
+fn farthest_tile_is_half_the_loop() {
+    for (sketch, farthest) in [(SQUARE, 4), (WINDING, 8)] {
+        let network = PipeNetwork::new(sketch).unwrap();
+        assert_eq!(network.find_furthest_point(), farthest);
+    }
+}
+
+#[test]
+fn readme_loops() {
+    // (sketch, loop tiles, twice the shoelace area, enclosed tiles)
+    let cases = [
+        (SQUARE, 8, 8, 1),
+        (WINDING, 16, 16, 1),
+        (ENCLOSED, 46, 52, 4),
+        (SQUEEZED, 44, 50, 4),
+        (LARGER, 140, 154, 8),
+        (JUNK, 160, 178, 10),
+    ];
+    for (sketch, tiles, double_area, enclosed) in cases {
+        let network = PipeNetwork::new(sketch).unwrap();
+        assert_eq!(network.trace_loop().len(), tiles);
+        assert_eq!(geometry::double_area(&network.polygon()), double_area);
+        assert_eq!(network.count_enclosed_tiles(), enclosed);
+    }
+}
+
+#[test]
+fn loop_walks_from_the_animal() {
+    let network = PipeNetwork::new(SQUARE).unwrap();
+    assert_eq!(network.animal(), position(1, 1));
+    assert_eq!(
+        network.trace_loop(),
+        [
+            position(1, 1),
+            position(1, 2),
+            position(1, 3),
+            position(2, 3),
+            position(3, 3),
+            position(3, 2),
+            position(3, 1),
+            position(2, 1),
+        ]
+    );
+}
+
+#[test]
+fn start_leaves_through_a_bend_that_opens_towards_it() {
+    // A `7` opens west and an `F` east, so only the one on the far side of
+    // each connects to `S`.
+    let network = PipeNetwork::new("7S7\n.||\n.LJ\n").unwrap();
+    let start = network.animal();
+    assert_eq!(network.follow_pipe(start, start), position(0, 2));
+    let network = PipeNetwork::new("FSF\n||.\nLJ.\n").unwrap();
+    let start = network.animal();
+    assert_eq!(network.follow_pipe(start, start), position(0, 0));
+    assert_eq!(network.count_enclosed_tiles(), 0);
+}
+
+#[test]
+fn sketches_need_an_animal() {
+    assert_eq!(PipeNetwork::new("F7\nLJ\n").unwrap_err(), "no animal");
+}
@@ -1,6 +1,6 @@
-use glam::IVec2;
+use aoc_utils::geometry::{self, Point};
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 enum MapPoint {
     Galaxy,
     EmptySpace,
@@ -8,16 +8,56 @@ enum MapPoint {
 
 #[derive(Debug)]
 struct StarMap {
-    map: IVec2,
+    map: Vec<Vec<MapPoint>>,
 }
 
 impl StarMap {
-    fn new(input: IVec2) -> Self {}
+    fn new(map: Vec<Vec<MapPoint>>) -> Self {
+        StarMap { map }
+    }
+
+    fn expanded_galaxies(&self, expansion: i64) -> Vec<Point> {
+        let empty_rows: Vec<usize> = self
+            .map
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| row.iter().all(|point| point == &MapPoint::EmptySpace))
+            .map(|(row_index, _)| row_index)
+            .collect();
+        let width = self.map.first().map(|row| row.len()).unwrap_or_default();
+        let empty_cols: Vec<usize> = (0..width)
+            .filter(|col_index| {
+                self.map
+                    .iter()
+                    .all(|row| row[*col_index] == MapPoint::EmptySpace)
+            })
+            .collect();
+
+        self.map
+            .iter()
+            .enumerate()
+            .flat_map(|(row_index, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, point)| point == &&MapPoint::Galaxy)
+                    .map(move |(col_index, _)| (row_index, col_index))
+            })
+            .map(|(row_index, col_index)| {
+                let rows_before = empty_rows.partition_point(|row| *row < row_index) as i64;
+                let cols_before = empty_cols.partition_point(|col| *col < col_index) as i64;
+                Point::new(
+                    col_index as i64 + cols_before * (expansion - 1),
+                    row_index as i64 + rows_before * (expansion - 1),
+                )
+            })
+            .collect()
+    }
 }
 
 fn parse_star_map(input: &str) -> StarMap {
     let map = input
         .split('\n')
+        .filter(|row| !row.is_empty())
         .map(|rows| {
             rows.chars()
                 .map(|char| match char {
@@ -34,5 +74,7 @@ fn parse_star_map(input: &str) -> StarMap {
 fn main() {
     let input = include_str!("input1.txt");
     let star_map = parse_star_map(input);
-    println!("{star_map:?}");
+    let galaxies = star_map.expanded_galaxies(2);
+    let result = geometry::manhattan_pair_sum(&galaxies);
+    println!("{result}");
 }